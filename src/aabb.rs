@@ -0,0 +1,269 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-aligned bounding boxes.
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{BaseNum, BaseFloat};
+use point::{Point2, Point3};
+use transform::Transform;
+use vector::{Vector2, Vector3};
+
+/// A two-dimensional axis-aligned bounding box.
+///
+/// The box is described by its `min` and `max` corners, and is considered to
+/// be closed - that is, points lying on its boundary are contained within it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb2<S> {
+    /// The corner of the box with the smallest coordinates.
+    pub min: Point2<S>,
+    /// The corner of the box with the largest coordinates.
+    pub max: Point2<S>,
+}
+
+/// A three-dimensional axis-aligned bounding box.
+///
+/// The box is described by its `min` and `max` corners, and is considered to
+/// be closed - that is, points lying on its boundary are contained within it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb3<S> {
+    /// The corner of the box with the smallest coordinates.
+    pub min: Point3<S>,
+    /// The corner of the box with the largest coordinates.
+    pub max: Point3<S>,
+}
+
+#[inline]
+fn partial_min<S: PartialOrd>(a: S, b: S) -> S {
+    if b < a { b } else { a }
+}
+
+#[inline]
+fn partial_max<S: PartialOrd>(a: S, b: S) -> S {
+    if b > a { b } else { a }
+}
+
+macro_rules! impl_aabb {
+    ($AabbN:ident, $PointN:ident, $VectorN:ident { $($field:ident),+ }) => {
+        impl<S: BaseNum> $AabbN<S> {
+            /// Construct a new bounding box from two opposite corners. The
+            /// corners are not required to be ordered.
+            #[inline]
+            pub fn new(p1: $PointN<S>, p2: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(partial_min(p1.$field, p2.$field)),+),
+                    max: $PointN::new($(partial_max(p1.$field, p2.$field)),+),
+                }
+            }
+
+            /// Construct a degenerate bounding box that contains only `point`.
+            #[inline]
+            pub fn from_point(point: $PointN<S>) -> $AabbN<S> {
+                $AabbN { min: point, max: point }
+            }
+
+            /// Construct the smallest bounding box that contains all of the
+            /// points in the slice. Returns `None` if the slice is empty.
+            #[inline]
+            pub fn from_points(points: &[$PointN<S>]) -> Option<$AabbN<S>> {
+                $AabbN::from_point_iter(points.iter().cloned())
+            }
+
+            /// Construct the smallest bounding box that contains all of the
+            /// points yielded by the iterator. Returns `None` if the iterator
+            /// is empty.
+            pub fn from_point_iter<I>(points: I) -> Option<$AabbN<S>>
+                where I: IntoIterator<Item = $PointN<S>>
+            {
+                let mut points = points.into_iter();
+                points.next().map(|first| {
+                    points.fold($AabbN::from_point(first), $AabbN::grow)
+                })
+            }
+
+            /// Return the smallest bounding box that contains both this box
+            /// and `point`.
+            #[inline]
+            #[must_use]
+            pub fn grow(self, point: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(partial_min(self.min.$field, point.$field)),+),
+                    max: $PointN::new($(partial_max(self.max.$field, point.$field)),+),
+                }
+            }
+
+            /// Return the smallest bounding box that contains both this box
+            /// and `other`.
+            #[inline]
+            #[must_use]
+            pub fn union(self, other: $AabbN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(partial_min(self.min.$field, other.min.$field)),+),
+                    max: $PointN::new($(partial_max(self.max.$field, other.max.$field)),+),
+                }
+            }
+
+            /// Return the region shared by this box and `other`, or `None` if
+            /// they do not overlap.
+            #[inline]
+            pub fn intersection(self, other: $AabbN<S>) -> Option<$AabbN<S>> {
+                if self.intersects(other) {
+                    Some($AabbN {
+                        min: $PointN::new($(partial_max(self.min.$field, other.min.$field)),+),
+                        max: $PointN::new($(partial_min(self.max.$field, other.max.$field)),+),
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Returns `true` if this box and `other` overlap. Boxes that only
+            /// touch along their boundaries are considered to overlap.
+            #[inline]
+            pub fn intersects(self, other: $AabbN<S>) -> bool {
+                $(self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)&&+
+            }
+
+            /// Returns `true` if `point` lies inside or on the boundary of the
+            /// box.
+            #[inline]
+            pub fn contains_point(self, point: $PointN<S>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+
+            /// Returns `true` if `other` lies entirely inside this box.
+            #[inline]
+            pub fn contains(self, other: $AabbN<S>) -> bool {
+                $(self.min.$field <= other.min.$field && other.max.$field <= self.max.$field)&&+
+            }
+
+            /// The point at the center of the box.
+            #[inline]
+            pub fn center(self) -> $PointN<S> {
+                self.min.midpoint(self.max)
+            }
+
+            /// The size of the box along each axis.
+            #[inline]
+            pub fn extent(self) -> $VectorN<S> {
+                self.max - self.min
+            }
+
+            /// Apply `transform` to the corners of the box, returning the
+            /// smallest axis-aligned box that contains the results.
+            ///
+            /// For affine transforms the result is guaranteed to contain every
+            /// transformed point of the original box, although it will
+            /// usually be larger than the tightest possible fit.
+            pub fn transform<T: Transform<$PointN<S>>>(&self, transform: &T) -> $AabbN<S> {
+                let corners = self.corners();
+                let first = $AabbN::from_point(transform.transform_point(corners[0]));
+                corners[1..].iter().fold(first, |aabb, &corner| {
+                    aabb.grow(transform.transform_point(corner))
+                })
+            }
+        }
+
+        impl<S: BaseFloat> AbsDiffEq for $AabbN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $PointN::abs_diff_eq(&self.min, &other.min, epsilon) &&
+                $PointN::abs_diff_eq(&self.max, &other.max, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $AabbN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $PointN::relative_eq(&self.min, &other.min, epsilon, max_relative) &&
+                $PointN::relative_eq(&self.max, &other.max, epsilon, max_relative)
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $AabbN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $PointN::ulps_eq(&self.min, &other.min, epsilon, max_ulps) &&
+                $PointN::ulps_eq(&self.max, &other.max, epsilon, max_ulps)
+            }
+        }
+    }
+}
+
+impl_aabb!(Aabb2, Point2, Vector2 { x, y });
+impl_aabb!(Aabb3, Point3, Vector3 { x, y, z });
+
+impl<S: BaseNum> Aabb2<S> {
+    /// The area enclosed by the box.
+    #[inline]
+    pub fn area(self) -> S {
+        self.extent().product()
+    }
+
+    /// The four corners of the box.
+    pub fn corners(&self) -> [Point2<S>; 4] {
+        let (min, max) = (self.min, self.max);
+        [
+            Point2::new(min.x, min.y),
+            Point2::new(max.x, min.y),
+            Point2::new(min.x, max.y),
+            Point2::new(max.x, max.y),
+        ]
+    }
+}
+
+impl<S: BaseNum> Aabb3<S> {
+    /// The volume enclosed by the box.
+    #[inline]
+    pub fn volume(self) -> S {
+        self.extent().product()
+    }
+
+    /// The eight corners of the box.
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            Point3::new(min.x, min.y, min.z),
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            Point3::new(max.x, max.y, max.z),
+        ]
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use matrix::{Matrix2, Matrix2x3, Matrix3, Matrix3x4, Matrix4};
+use num::BaseFloat;
+use point::{Point2, Point3};
+use rotation::{Rotation2, Rotation3};
+use transform::{Decomposed, Transform, Transform2, Transform3};
+use vector::{Vector2, Vector3};
+
+/// An affine transformation in two dimensions, stored as a linear map followed
+/// by a translation.
+///
+/// This is equivalent to a `Matrix3` whose bottom row is `(0, 0, 1)`, but
+/// composing and inverting it skips the work involving that row.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Affine2<S> {
+    pub linear: Matrix2<S>,
+    pub translation: Vector2<S>,
+}
+
+/// An affine transformation in three dimensions, stored as a linear map
+/// followed by a translation.
+///
+/// This is equivalent to a `Matrix4` whose bottom row is `(0, 0, 0, 1)`, but
+/// composing and inverting it skips the work involving that row.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Affine3<S> {
+    pub linear: Matrix3<S>,
+    pub translation: Vector3<S>,
+}
+
+macro_rules! impl_affine {
+    ($AffineN:ident, $PointN:ident, $VectorN:ident, $MatrixN:ident, $TransformN:ident) => {
+        impl<S: BaseFloat> $AffineN<S> {
+            /// Construct an affine transformation from a linear map and a
+            /// translation.
+            #[inline]
+            pub fn new(linear: $MatrixN<S>, translation: $VectorN<S>) -> $AffineN<S> {
+                $AffineN { linear, translation }
+            }
+
+            /// An affine transformation that only translates.
+            #[inline]
+            pub fn from_translation(translation: $VectorN<S>) -> $AffineN<S> {
+                $AffineN::new($MatrixN::identity(), translation)
+            }
+
+            /// An affine transformation that only applies a linear map.
+            #[inline]
+            pub fn from_linear(linear: $MatrixN<S>) -> $AffineN<S> {
+                $AffineN::new(linear, $VectorN::zero())
+            }
+
+            /// The matrix that transforms normal vectors, which is the inverse
+            /// transpose of the linear part. Returns `None` if the linear part
+            /// is not invertible.
+            ///
+            /// Normals transformed by this matrix are perpendicular to the
+            /// transformed surface, but are not of unit length if the
+            /// transformation scales.
+            #[inline]
+            pub fn normal_matrix(&self) -> Option<$MatrixN<S>> {
+                Some(self.linear.invert()?.transpose())
+            }
+        }
+
+        impl<S: BaseFloat> Transform<$PointN<S>> for $AffineN<S> {
+            #[inline]
+            fn one() -> $AffineN<S> {
+                $AffineN::from_linear($MatrixN::identity())
+            }
+
+            #[inline]
+            fn look_at(eye: $PointN<S>, center: $PointN<S>, up: $VectorN<S>) -> $AffineN<S> {
+                $AffineN::view(eye, center, up)
+            }
+
+            #[inline]
+            fn transform_vector(&self, vec: $VectorN<S>) -> $VectorN<S> {
+                self.linear * vec
+            }
+
+            #[inline]
+            fn transform_point(&self, point: $PointN<S>) -> $PointN<S> {
+                $PointN::from_vec(self.linear * point.to_vec() + self.translation)
+            }
+
+            #[inline]
+            fn concat(&self, other: &$AffineN<S>) -> $AffineN<S> {
+                $AffineN::new(self.linear * other.linear,
+                              self.linear * other.translation + self.translation)
+            }
+
+            #[inline]
+            fn inverse_transform(&self) -> Option<$AffineN<S>> {
+                let linear = self.linear.invert()?;
+                Some($AffineN::new(linear, -(linear * self.translation)))
+            }
+        }
+
+        impl<S: BaseFloat> $TransformN<S> for $AffineN<S> {}
+
+        impl<S: BaseFloat> AbsDiffEq for $AffineN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $MatrixN::abs_diff_eq(&self.linear, &other.linear, epsilon) &&
+                $VectorN::abs_diff_eq(&self.translation, &other.translation, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $AffineN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $MatrixN::relative_eq(&self.linear, &other.linear, epsilon, max_relative) &&
+                $VectorN::relative_eq(&self.translation, &other.translation, epsilon, max_relative)
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $AffineN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $MatrixN::ulps_eq(&self.linear, &other.linear, epsilon, max_ulps) &&
+                $VectorN::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+            }
+        }
+    }
+}
+
+impl_affine!(Affine2, Point2, Vector2, Matrix2, Transform2);
+impl_affine!(Affine3, Point3, Vector3, Matrix3, Transform3);
+
+impl<S: BaseFloat> Affine2<S> {
+    /// Split a `Matrix3` into its linear part and translation, returning
+    /// `None` if its bottom row is not `(0, 0, 1)`.
+    pub fn from_matrix3(m: Matrix3<S>) -> Option<Affine2<S>> {
+        let affine = ulps_eq!(m.x.z, &S::zero()) && ulps_eq!(m.y.z, &S::zero()) &&
+                     ulps_eq!(m.z.z, &S::one());
+        if affine {
+            Some(Affine2::new(Matrix2::from_cols(m.x.truncate(), m.y.truncate()), m.z.truncate()))
+        } else {
+            None
+        }
+    }
+
+    fn view(eye: Point2<S>, center: Point2<S>, up: Vector2<S>) -> Affine2<S> {
+        let linear = Matrix2::look_at(center - eye, up);
+        Affine2::new(linear, linear * (Point2::origin() - eye))
+    }
+}
+
+impl<S: BaseFloat> Affine3<S> {
+    /// Split a `Matrix4` into its linear part and translation, returning
+    /// `None` if its bottom row is not `(0, 0, 0, 1)`.
+    pub fn from_matrix4(m: Matrix4<S>) -> Option<Affine3<S>> {
+        let affine = ulps_eq!(m.x.w, &S::zero()) && ulps_eq!(m.y.w, &S::zero()) &&
+                     ulps_eq!(m.z.w, &S::zero()) && ulps_eq!(m.w.w, &S::one());
+        if affine {
+            let linear = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+            Some(Affine3::new(linear, m.w.truncate()))
+        } else {
+            None
+        }
+    }
+
+    /// Matches `Matrix4::look_at`, which looks down the negative `z` axis.
+    fn view(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Affine3<S> {
+        let f = (center - eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+        let linear = Matrix3::from_cols(s, u, -f).transpose();
+        Affine3::new(linear, linear * (Point3::origin() - eye))
+    }
+}
+
+impl<S: BaseFloat> From<Affine2<S>> for Matrix3<S> {
+    #[inline]
+    fn from(affine: Affine2<S>) -> Matrix3<S> {
+        Matrix3::from_cols(affine.linear.x.extend(S::zero()),
+                           affine.linear.y.extend(S::zero()),
+                           affine.translation.extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat> From<Affine3<S>> for Matrix4<S> {
+    #[inline]
+    fn from(affine: Affine3<S>) -> Matrix4<S> {
+        Matrix4::from_cols(affine.linear.x.extend(S::zero()),
+                           affine.linear.y.extend(S::zero()),
+                           affine.linear.z.extend(S::zero()),
+                           affine.translation.extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat> From<Affine2<S>> for Matrix2x3<S> {
+    #[inline]
+    fn from(affine: Affine2<S>) -> Matrix2x3<S> {
+        Matrix2x3::from_cols(affine.linear.x, affine.linear.y, affine.translation)
+    }
+}
+
+impl<S: BaseFloat> From<Matrix2x3<S>> for Affine2<S> {
+    #[inline]
+    fn from(m: Matrix2x3<S>) -> Affine2<S> {
+        Affine2::new(Matrix2::from_cols(m.x, m.y), m.z)
+    }
+}
+
+impl<S: BaseFloat> From<Affine3<S>> for Matrix3x4<S> {
+    #[inline]
+    fn from(affine: Affine3<S>) -> Matrix3x4<S> {
+        Matrix3x4::from_cols(affine.linear.x, affine.linear.y, affine.linear.z, affine.translation)
+    }
+}
+
+impl<S: BaseFloat> From<Matrix3x4<S>> for Affine3<S> {
+    #[inline]
+    fn from(m: Matrix3x4<S>) -> Affine3<S> {
+        Affine3::new(Matrix3::from_cols(m.x, m.y, m.z), m.w)
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<S>> From<Decomposed<Vector2<S>, R>> for Affine2<S> {
+    #[inline]
+    fn from(dec: Decomposed<Vector2<S>, R>) -> Affine2<S> {
+        let m: Matrix2<S> = dec.rot.into();
+        Affine2::new(m * dec.scale, dec.disp)
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> From<Decomposed<Vector3<S>, R>> for Affine3<S> {
+    #[inline]
+    fn from(dec: Decomposed<Vector3<S>, R>) -> Affine3<S> {
+        let m: Matrix3<S> = dec.rot.into();
+        Affine3::new(m * dec.scale, dec.disp)
+    }
+}
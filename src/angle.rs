@@ -15,18 +15,20 @@
 
 //! Angle units for type-safe, self-documenting code.
 
-use std::fmt;
-use std::f64;
-use std::iter;
-use std::ops::*;
+use core::fmt;
+use core::f64;
+use core::iter;
+use core::ops::*;
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
+#[cfg(feature = "rand")]
 use rand::distributions::range::SampleRange;
 use num_traits::{cast, Bounded};
 
 use structure::*;
 
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num::BaseFloat;
 
 /// An angle, in radians.
@@ -164,7 +166,7 @@ macro_rules! impl_angle {
             fn div_assign(&mut self, scalar) { self.0 /= scalar; }
         });
 
-        impl<S: BaseFloat> ApproxEq for $Angle<S> {
+        impl<S: BaseFloat> AbsDiffEq for $Angle<S> {
             type Epsilon = S::Epsilon;
 
             #[inline]
@@ -173,19 +175,28 @@ macro_rules! impl_angle {
             }
 
             #[inline]
-            fn default_max_relative() -> S::Epsilon {
-                S::default_max_relative()
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                S::abs_diff_eq(&self.0, &other.0, epsilon)
             }
+        }
 
+        impl<S: BaseFloat> RelativeEq for $Angle<S> {
             #[inline]
-            fn default_max_ulps() -> u32 {
-                S::default_max_ulps()
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
             }
 
             #[inline]
             fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
                 S::relative_eq(&self.0, &other.0, epsilon, max_relative)
             }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $Angle<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
 
             #[inline]
             fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -193,6 +204,7 @@ macro_rules! impl_angle {
             }
         }
 
+        #[cfg(feature = "rand")]
         impl<S: BaseFloat + SampleRange> Rand for $Angle<S> {
             #[inline]
             fn rand<R: Rng>(rng: &mut R) -> $Angle<S> {
@@ -0,0 +1,604 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SIMD fast paths for `f32` vectors, matrices and quaternions, enabled by
+//! the `simd` feature.
+//!
+//! The kernels are selected at compile time from the target features that
+//! are enabled: SSE2 (the default on `x86_64`) for all of them, and AVX for
+//! `Matrix4` multiplication when building with `-C target-feature=+avx`.
+//! Other targets use scalar kernels.
+//!
+//! Apart from the explicitly approximate `rsqrt_element_wide` and
+//! `recip_element_wide`, every kernel performs the same floating point
+//! operations in the same order as the generic implementation, so the
+//! results are bitwise identical with and without the `simd` feature.
+
+use core::any::TypeId;
+use core::marker::PhantomData;
+use core::mem;
+use core::slice;
+
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use vector::{Vector3, Vector4};
+
+/// The `TypeId` of `T`, without requiring `T: 'static` like `TypeId::of`
+/// does, so that the scalar traits don't need to either. The lifetimes of `T`
+/// are erased, which is fine for comparing against types without lifetimes.
+#[inline(always)]
+fn type_id<T: ?Sized>() -> TypeId {
+    trait NonStaticAny {
+        fn type_id(&self) -> TypeId where Self: 'static;
+    }
+
+    impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+        fn type_id(&self) -> TypeId where Self: 'static {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom = PhantomData::<T>;
+    let erased = unsafe {
+        mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom)
+    };
+    erased.type_id()
+}
+
+/// Returns `true` if `S` is `f32`, which is resolved at compile time.
+#[inline(always)]
+fn is_f32<S>() -> bool {
+    type_id::<S>() == TypeId::of::<f32>()
+}
+
+/// Returns `true` if `V` is `Vector4<f32>` or `Quaternion<f32>`, which both
+/// have the layout of `[f32; 4]`. This is resolved at compile time.
+#[inline(always)]
+fn is_f32x4<V>() -> bool {
+    let id = type_id::<V>();
+    id == TypeId::of::<Vector4<f32>>() || id == TypeId::of::<Quaternion<f32>>()
+}
+
+/// Reinterpret a value as a type with the same layout.
+#[inline(always)]
+unsafe fn reinterpret<S, T>(value: S) -> T {
+    debug_assert_eq!(mem::size_of::<S>(), mem::size_of::<T>());
+    let result = mem::transmute_copy(&value);
+    mem::forget(value);
+    result
+}
+
+/// Reinterpret a slice as a slice of a type with the same layout.
+#[inline(always)]
+unsafe fn reinterpret_slice<S, T>(values: &mut [S]) -> &mut [T] {
+    debug_assert_eq!(mem::size_of::<S>(), mem::size_of::<T>());
+    slice::from_raw_parts_mut(values.as_mut_ptr() as *mut T, values.len())
+}
+
+/// `m * v`, if `S` is `f32`.
+#[inline]
+pub fn mul_matrix4_vector4<S: BaseFloat>(m: Matrix4<S>, v: Vector4<S>) -> Option<Vector4<S>> {
+    if is_f32::<S>() {
+        unsafe { Some(reinterpret(kernels::mul_matrix4_vector4(reinterpret(m), reinterpret(v)))) }
+    } else {
+        None
+    }
+}
+
+/// `a * b`, if `S` is `f32`.
+#[inline]
+pub fn mul_matrix4<S: BaseFloat>(a: Matrix4<S>, b: Matrix4<S>) -> Option<Matrix4<S>> {
+    if is_f32::<S>() {
+        unsafe { Some(reinterpret(kernels::mul_matrix4(reinterpret(a), reinterpret(b)))) }
+    } else {
+        None
+    }
+}
+
+/// `a * b`, if `S` is `f32`.
+#[inline]
+pub fn mul_quaternion<S: BaseFloat>(a: Quaternion<S>, b: Quaternion<S>) -> Option<Quaternion<S>> {
+    if is_f32::<S>() {
+        unsafe { Some(reinterpret(kernels::mul_quaternion(reinterpret(a), reinterpret(b)))) }
+    } else {
+        None
+    }
+}
+
+/// Replace each point with `m.transform_point(point)`, if `S` is `f32`.
+#[inline]
+pub fn transform_points<S: BaseFloat>(m: &Matrix4<S>, points: &mut [Point3<S>]) -> bool {
+    if is_f32::<S>() {
+        unsafe { kernels::transform_points(reinterpret(*m), reinterpret_slice(points)) };
+        true
+    } else {
+        false
+    }
+}
+
+/// Replace each vector with `m.transform_vector(vector)`, if `S` is `f32`.
+#[inline]
+pub fn transform_vectors<S: BaseFloat>(m: &Matrix4<S>, vectors: &mut [Vector3<S>]) -> bool {
+    if is_f32::<S>() {
+        unsafe { kernels::transform_vectors(reinterpret(*m), reinterpret_slice(vectors)) };
+        true
+    } else {
+        false
+    }
+}
+
+/// Replace each vector with `q.rotate_vector(vector)`, if `S` is `f32`.
+#[inline]
+pub fn rotate_vectors<S: BaseFloat>(q: &Quaternion<S>, vectors: &mut [Vector3<S>]) -> bool {
+    if is_f32::<S>() {
+        unsafe { kernels::rotate_vectors(reinterpret(*q), reinterpret_slice(vectors)) };
+        true
+    } else {
+        false
+    }
+}
+
+/// Replace each point with `q.rotate_point(point)`, if `S` is `f32`.
+#[inline]
+pub fn rotate_points<S: BaseFloat>(q: &Quaternion<S>, points: &mut [Point3<S>]) -> bool {
+    // `Point3` and `Vector3` have the same layout
+    rotate_vectors(q, unsafe { reinterpret_slice(points) })
+}
+
+/// Define dispatch functions for kernels that operate on four `f32` lanes,
+/// and optionally an `f32` scalar.
+macro_rules! dispatch_f32x4 {
+    (@fn $(#[$attr:meta])* fn $name:ident<$($T:ident),+>($($arg:ident: $Arg:ident),+) -> $Output:ident
+        if $condition:expr) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $name<$($T),+>($($arg: $Arg),+) -> Option<$Output> {
+            if $condition {
+                unsafe { Some(reinterpret(kernels::$name($(reinterpret($arg)),+))) }
+            } else {
+                None
+            }
+        }
+    };
+    ($($(#[$attr:meta])* fn $name:ident<V>($($arg:ident: $Arg:ident),+) -> $Output:ident;)+) => {
+        $(dispatch_f32x4!(@fn $(#[$attr])* fn $name<V>($($arg: $Arg),+) -> $Output
+            if is_f32x4::<V>());)+
+    };
+    ($($(#[$attr:meta])* fn $name:ident<V, S>($($arg:ident: $Arg:ident),+) -> $Output:ident;)+) => {
+        $(dispatch_f32x4!(@fn $(#[$attr])* fn $name<V, S>($($arg: $Arg),+) -> $Output
+            if is_f32x4::<V>() && is_f32::<S>());)+
+    };
+}
+
+dispatch_f32x4! {
+    /// `a + b`, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn add<V>(a: V, b: V) -> V;
+    /// `a - b`, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn sub<V>(a: V, b: V) -> V;
+    /// `a * b` component-wise, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn mul<V>(a: V, b: V) -> V;
+    /// `a / b` component-wise, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn div<V>(a: V, b: V) -> V;
+}
+
+/// `-a`, if `V` is `Vector4<f32>` or `Quaternion<f32>`. This borrows `a`, as
+/// the vector negation doesn't require its scalar to be `Copy`.
+#[inline]
+pub fn neg<V>(a: &V) -> Option<V> {
+    if is_f32x4::<V>() {
+        unsafe { Some(reinterpret(kernels::neg(*(a as *const V as *const Vector4<f32>)))) }
+    } else {
+        None
+    }
+}
+
+dispatch_f32x4! {
+    /// `a + s` for each component, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn add_scalar<V, S>(a: V, s: S) -> V;
+    /// `a - s` for each component, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn sub_scalar<V, S>(a: V, s: S) -> V;
+    /// `a * s`, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn mul_scalar<V, S>(a: V, s: S) -> V;
+    /// `a / s`, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn div_scalar<V, S>(a: V, s: S) -> V;
+    /// `s * a`, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn scalar_mul<V, S>(s: S, a: V) -> V;
+    /// `s / a` for each component, if `V` is `Vector4<f32>` or `Quaternion<f32>`.
+    fn scalar_div<V, S>(s: S, a: V) -> V;
+}
+
+/// `a.dot(b)`, if `V` is `Vector4<f32>` or `Quaternion<f32>`. The two types
+/// sum their products in a different order, so they use different kernels.
+#[inline]
+pub fn dot<V, S>(a: V, b: V) -> Option<S> {
+    if !is_f32::<S>() {
+        None
+    } else if type_id::<V>() == TypeId::of::<Vector4<f32>>() {
+        unsafe { Some(reinterpret(kernels::dot(reinterpret(a), reinterpret(b)))) }
+    } else if type_id::<V>() == TypeId::of::<Quaternion<f32>>() {
+        unsafe { Some(reinterpret(kernels::dot_quaternion(reinterpret(a), reinterpret(b)))) }
+    } else {
+        None
+    }
+}
+
+pub use self::kernels::{recip, rsqrt, sqrt};
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+mod kernels {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use matrix::Matrix4;
+    use point::Point3;
+    use quaternion::Quaternion;
+    use vector::{Vector3, Vector4};
+
+    #[inline(always)]
+    unsafe fn load(v: Vector4<f32>) -> __m128 {
+        _mm_set_ps(v.w, v.z, v.y, v.x)
+    }
+
+    #[inline(always)]
+    unsafe fn store(v: __m128) -> Vector4<f32> {
+        let mut out = [0.0f32; 4];
+        _mm_storeu_ps(out.as_mut_ptr(), v);
+        out.into()
+    }
+
+    #[inline(always)]
+    unsafe fn columns(m: Matrix4<f32>) -> [__m128; 4] {
+        [load(m.x), load(m.y), load(m.z), load(m.w)]
+    }
+
+    /// `((c0 * v.x + c1 * v.y) + c2 * v.z) + c3 * v.w`, one row per lane.
+    #[inline(always)]
+    unsafe fn combine(c: &[__m128; 4], v: Vector4<f32>) -> __m128 {
+        let r = _mm_mul_ps(c[0], _mm_set1_ps(v.x));
+        let r = _mm_add_ps(r, _mm_mul_ps(c[1], _mm_set1_ps(v.y)));
+        let r = _mm_add_ps(r, _mm_mul_ps(c[2], _mm_set1_ps(v.z)));
+        _mm_add_ps(r, _mm_mul_ps(c[3], _mm_set1_ps(v.w)))
+    }
+
+    #[inline]
+    pub unsafe fn mul_matrix4_vector4(m: Matrix4<f32>, v: Vector4<f32>) -> Vector4<f32> {
+        store(combine(&columns(m), v))
+    }
+
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    pub unsafe fn mul_matrix4(a: Matrix4<f32>, b: Matrix4<f32>) -> Matrix4<f32> {
+        let c = columns(a);
+        Matrix4::from_cols(store(combine(&c, b.x)), store(combine(&c, b.y)),
+                           store(combine(&c, b.z)), store(combine(&c, b.w)))
+    }
+
+    /// Computes two columns of the product at a time.
+    #[cfg(target_feature = "avx")]
+    #[inline]
+    pub unsafe fn mul_matrix4(a: Matrix4<f32>, b: Matrix4<f32>) -> Matrix4<f32> {
+        let c = columns(a);
+        let c = [_mm256_set_m128(c[0], c[0]), _mm256_set_m128(c[1], c[1]),
+                 _mm256_set_m128(c[2], c[2]), _mm256_set_m128(c[3], c[3])];
+        let pair = |lo: Vector4<f32>, hi: Vector4<f32>| {
+            let splat = |l: f32, h: f32| _mm256_set_m128(_mm_set1_ps(h), _mm_set1_ps(l));
+            let r = _mm256_mul_ps(c[0], splat(lo.x, hi.x));
+            let r = _mm256_add_ps(r, _mm256_mul_ps(c[1], splat(lo.y, hi.y)));
+            let r = _mm256_add_ps(r, _mm256_mul_ps(c[2], splat(lo.z, hi.z)));
+            let r = _mm256_add_ps(r, _mm256_mul_ps(c[3], splat(lo.w, hi.w)));
+            (store(_mm256_castps256_ps128(r)), store(_mm256_extractf128_ps(r, 1)))
+        };
+        let (x, y) = pair(b.x, b.y);
+        let (z, w) = pair(b.z, b.w);
+        Matrix4::from_cols(x, y, z, w)
+    }
+
+    /// The lanes hold `(s, x, y, z)`, and each lane sums its four products in
+    /// the same order as the scalar implementation, with subtractions done by
+    /// flipping the sign bit of the product.
+    #[inline]
+    pub unsafe fn mul_quaternion(a: Quaternion<f32>, b: Quaternion<f32>) -> Quaternion<f32> {
+        let a = _mm_set_ps(a.v.z, a.v.y, a.v.x, a.s);
+        let b = _mm_set_ps(b.v.z, b.v.y, b.v.x, b.s);
+        let negate_first = _mm_set_ps(0.0, 0.0, 0.0, -0.0);
+        let negate_all = _mm_set1_ps(-0.0);
+
+        // (s1 s2, s1 x2, s1 y2, s1 z2)
+        let t0 = _mm_mul_ps(_mm_shuffle_ps(a, a, 0b00_00_00_00), b);
+        // (-x1 x2, x1 s2, y1 s2, z1 s2)
+        let t1 = _mm_mul_ps(_mm_shuffle_ps(a, a, 0b11_10_01_01), _mm_shuffle_ps(b, b, 0b00_00_00_01));
+        let t1 = _mm_xor_ps(t1, negate_first);
+        // (-y1 y2, y1 z2, z1 x2, x1 y2)
+        let t2 = _mm_mul_ps(_mm_shuffle_ps(a, a, 0b01_11_10_10), _mm_shuffle_ps(b, b, 0b10_01_11_10));
+        let t2 = _mm_xor_ps(t2, negate_first);
+        // (-z1 z2, -z1 y2, -x1 z2, -y1 x2)
+        let t3 = _mm_mul_ps(_mm_shuffle_ps(a, a, 0b10_01_11_11), _mm_shuffle_ps(b, b, 0b01_11_10_11));
+        let t3 = _mm_xor_ps(t3, negate_all);
+
+        let r = store(_mm_add_ps(_mm_add_ps(_mm_add_ps(t0, t1), t2), t3));
+        Quaternion::new(r.x, r.y, r.z, r.w)
+    }
+
+    /// Each point is divided by its `w` component by multiplying with the
+    /// reciprocal, like `Point3::from_homogeneous`.
+    #[inline]
+    pub unsafe fn transform_points(m: Matrix4<f32>, points: &mut [Point3<f32>]) {
+        let c = columns(m);
+        let one = _mm_set1_ps(1.0);
+        for p in points {
+            let r = combine(&c, Vector4::new(p.x, p.y, p.z, 1.0));
+            let r = store(_mm_mul_ps(r, _mm_div_ps(one, _mm_shuffle_ps(r, r, 0b11_11_11_11))));
+            *p = Point3::new(r.x, r.y, r.z);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn transform_vectors(m: Matrix4<f32>, vectors: &mut [Vector3<f32>]) {
+        let c = columns(m);
+        for v in vectors {
+            *v = store(combine(&c, v.extend(0.0))).truncate();
+        }
+    }
+
+    /// `a × b` in the first three lanes.
+    #[inline(always)]
+    unsafe fn cross(a: __m128, b: __m128) -> __m128 {
+        let yzx = |v| _mm_shuffle_ps(v, v, 0b11_00_10_01);
+        let zxy = |v| _mm_shuffle_ps(v, v, 0b11_01_00_10);
+        _mm_sub_ps(_mm_mul_ps(yzx(a), zxy(b)), _mm_mul_ps(zxy(a), yzx(b)))
+    }
+
+    /// Uses the same formula as `Quaternion * Vector3`.
+    #[inline]
+    pub unsafe fn rotate_vectors(q: Quaternion<f32>, vectors: &mut [Vector3<f32>]) {
+        let qv = load(q.v.extend(0.0));
+        let s = _mm_set1_ps(q.s);
+        let two = _mm_set1_ps(2.0);
+        for v in vectors {
+            let r = load(v.extend(0.0));
+            let tmp = _mm_add_ps(cross(qv, r), _mm_mul_ps(r, s));
+            *v = store(_mm_add_ps(_mm_mul_ps(cross(qv, tmp), two), r)).truncate();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn add(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_add_ps(load(a), load(b)))
+    }
+
+    #[inline]
+    pub unsafe fn sub(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_sub_ps(load(a), load(b)))
+    }
+
+    #[inline]
+    pub unsafe fn mul(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_mul_ps(load(a), load(b)))
+    }
+
+    #[inline]
+    pub unsafe fn div(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_div_ps(load(a), load(b)))
+    }
+
+    /// Flips the sign bits, like scalar negation.
+    #[inline]
+    pub unsafe fn neg(a: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_xor_ps(load(a), _mm_set1_ps(-0.0)))
+    }
+
+    #[inline]
+    pub unsafe fn add_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        store(_mm_add_ps(load(a), _mm_set1_ps(s)))
+    }
+
+    #[inline]
+    pub unsafe fn sub_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        store(_mm_sub_ps(load(a), _mm_set1_ps(s)))
+    }
+
+    #[inline]
+    pub unsafe fn mul_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        store(_mm_mul_ps(load(a), _mm_set1_ps(s)))
+    }
+
+    #[inline]
+    pub unsafe fn div_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        store(_mm_div_ps(load(a), _mm_set1_ps(s)))
+    }
+
+    #[inline]
+    pub unsafe fn scalar_mul(s: f32, a: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_mul_ps(_mm_set1_ps(s), load(a)))
+    }
+
+    #[inline]
+    pub unsafe fn scalar_div(s: f32, a: Vector4<f32>) -> Vector4<f32> {
+        store(_mm_div_ps(_mm_set1_ps(s), load(a)))
+    }
+
+    /// `((p0 + p1) + p2) + p3`, where `p` is the component-wise product.
+    #[inline]
+    pub unsafe fn dot(a: Vector4<f32>, b: Vector4<f32>) -> f32 {
+        let p = _mm_mul_ps(load(a), load(b));
+        let s = _mm_add_ss(p, _mm_shuffle_ps(p, p, 0b11_10_01_01));
+        let s = _mm_add_ss(s, _mm_movehl_ps(p, p));
+        _mm_cvtss_f32(_mm_add_ss(s, _mm_shuffle_ps(p, p, 0b11_10_01_11)))
+    }
+
+    /// `p0 + ((p1 + p2) + p3)`, where `p` is the component-wise product of
+    /// two quaternions stored as `(s, x, y, z)`.
+    #[inline]
+    pub unsafe fn dot_quaternion(a: Vector4<f32>, b: Vector4<f32>) -> f32 {
+        let p = _mm_mul_ps(load(a), load(b));
+        let v = _mm_shuffle_ps(p, p, 0b11_10_01_01);
+        let v = _mm_add_ss(v, _mm_movehl_ps(p, p));
+        let v = _mm_add_ss(v, _mm_shuffle_ps(p, p, 0b11_10_01_11));
+        _mm_cvtss_f32(_mm_add_ss(p, v))
+    }
+
+    #[inline]
+    pub fn sqrt(v: Vector4<f32>) -> Vector4<f32> {
+        unsafe { store(_mm_sqrt_ps(load(v))) }
+    }
+
+    #[inline]
+    pub fn rsqrt(v: Vector4<f32>) -> Vector4<f32> {
+        unsafe { store(_mm_rsqrt_ps(load(v))) }
+    }
+
+    #[inline]
+    pub fn recip(v: Vector4<f32>) -> Vector4<f32> {
+        unsafe { store(_mm_rcp_ps(load(v))) }
+    }
+}
+
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2")))]
+mod kernels {
+    use num_traits::Float;
+
+    use structure::*;
+
+    use matrix::Matrix4;
+    use point::Point3;
+    use quaternion::Quaternion;
+    use rotation::Rotation;
+    use transform::Transform;
+    use vector::{Vector3, Vector4};
+
+    #[inline]
+    pub unsafe fn mul_matrix4_vector4(m: Matrix4<f32>, v: Vector4<f32>) -> Vector4<f32> {
+        m.x * v.x + m.y * v.y + m.z * v.z + m.w * v.w
+    }
+
+    #[inline]
+    pub unsafe fn mul_matrix4(a: Matrix4<f32>, b: Matrix4<f32>) -> Matrix4<f32> {
+        Matrix4::from_cols(mul_matrix4_vector4(a, b.x), mul_matrix4_vector4(a, b.y),
+                           mul_matrix4_vector4(a, b.z), mul_matrix4_vector4(a, b.w))
+    }
+
+    #[inline]
+    pub unsafe fn mul_quaternion(a: Quaternion<f32>, b: Quaternion<f32>) -> Quaternion<f32> {
+        Quaternion::new(a.s * b.s - a.v.x * b.v.x - a.v.y * b.v.y - a.v.z * b.v.z,
+                        a.s * b.v.x + a.v.x * b.s + a.v.y * b.v.z - a.v.z * b.v.y,
+                        a.s * b.v.y + a.v.y * b.s + a.v.z * b.v.x - a.v.x * b.v.z,
+                        a.s * b.v.z + a.v.z * b.s + a.v.x * b.v.y - a.v.y * b.v.x)
+    }
+
+    #[inline]
+    pub unsafe fn transform_points(m: Matrix4<f32>, points: &mut [Point3<f32>]) {
+        for p in points {
+            *p = m.transform_point(*p);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn transform_vectors(m: Matrix4<f32>, vectors: &mut [Vector3<f32>]) {
+        for v in vectors {
+            *v = m.transform_vector(*v);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn rotate_vectors(q: Quaternion<f32>, vectors: &mut [Vector3<f32>]) {
+        for v in vectors {
+            *v = q.rotate_vector(*v);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn add(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(a.x + b.x, a.y + b.y, a.z + b.z, a.w + b.w)
+    }
+
+    #[inline]
+    pub unsafe fn sub(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(a.x - b.x, a.y - b.y, a.z - b.z, a.w - b.w)
+    }
+
+    #[inline]
+    pub unsafe fn mul(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(a.x * b.x, a.y * b.y, a.z * b.z, a.w * b.w)
+    }
+
+    #[inline]
+    pub unsafe fn div(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(a.x / b.x, a.y / b.y, a.z / b.z, a.w / b.w)
+    }
+
+    #[inline]
+    pub unsafe fn neg(a: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(-a.x, -a.y, -a.z, -a.w)
+    }
+
+    #[inline]
+    pub unsafe fn add_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        Vector4::new(a.x + s, a.y + s, a.z + s, a.w + s)
+    }
+
+    #[inline]
+    pub unsafe fn sub_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        Vector4::new(a.x - s, a.y - s, a.z - s, a.w - s)
+    }
+
+    #[inline]
+    pub unsafe fn mul_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        Vector4::new(a.x * s, a.y * s, a.z * s, a.w * s)
+    }
+
+    #[inline]
+    pub unsafe fn div_scalar(a: Vector4<f32>, s: f32) -> Vector4<f32> {
+        Vector4::new(a.x / s, a.y / s, a.z / s, a.w / s)
+    }
+
+    #[inline]
+    pub unsafe fn scalar_mul(s: f32, a: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(s * a.x, s * a.y, s * a.z, s * a.w)
+    }
+
+    #[inline]
+    pub unsafe fn scalar_div(s: f32, a: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(s / a.x, s / a.y, s / a.z, s / a.w)
+    }
+
+    #[inline]
+    pub unsafe fn dot(a: Vector4<f32>, b: Vector4<f32>) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    }
+
+    #[inline]
+    pub unsafe fn dot_quaternion(a: Vector4<f32>, b: Vector4<f32>) -> f32 {
+        a.x * b.x + (a.y * b.y + a.z * b.z + a.w * b.w)
+    }
+
+    #[inline]
+    pub fn sqrt(v: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(Float::sqrt(v.x), Float::sqrt(v.y), Float::sqrt(v.z), Float::sqrt(v.w))
+    }
+
+    #[inline]
+    pub fn rsqrt(v: Vector4<f32>) -> Vector4<f32> {
+        recip(sqrt(v))
+    }
+
+    #[inline]
+    pub fn recip(v: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(v.x.recip(), v.y.recip(), v.z.recip(), v.w.recip())
+    }
+}
@@ -82,3 +82,45 @@ pub fn array3x3<T, A: Into<[[T; 3]; 3]>>(value: A) -> [[T; 3]; 3] {
 pub fn array4x4<T, A: Into<[[T; 4]; 4]>>(value: A) -> [[T; 4]; 4] {
     value.into()
 }
+
+/// Force a conversion into a 3-element array of 2-element columns, as
+/// used by `Matrix2x3`.
+#[inline]
+pub fn array2x3<T, A: Into<[[T; 2]; 3]>>(value: A) -> [[T; 2]; 3] {
+    value.into()
+}
+
+/// Force a conversion into a 4-element array of 2-element columns, as
+/// used by `Matrix2x4`.
+#[inline]
+pub fn array2x4<T, A: Into<[[T; 2]; 4]>>(value: A) -> [[T; 2]; 4] {
+    value.into()
+}
+
+/// Force a conversion into a 2-element array of 3-element columns, as
+/// used by `Matrix3x2`.
+#[inline]
+pub fn array3x2<T, A: Into<[[T; 3]; 2]>>(value: A) -> [[T; 3]; 2] {
+    value.into()
+}
+
+/// Force a conversion into a 4-element array of 3-element columns, as
+/// used by `Matrix3x4`.
+#[inline]
+pub fn array3x4<T, A: Into<[[T; 3]; 4]>>(value: A) -> [[T; 3]; 4] {
+    value.into()
+}
+
+/// Force a conversion into a 2-element array of 4-element columns, as
+/// used by `Matrix4x2`.
+#[inline]
+pub fn array4x2<T, A: Into<[[T; 4]; 2]>>(value: A) -> [[T; 4]; 2] {
+    value.into()
+}
+
+/// Force a conversion into a 3-element array of 4-element columns, as
+/// used by `Matrix4x3`.
+#[inline]
+pub fn array4x3<T, A: Into<[[T; 4]; 3]>>(value: A) -> [[T; 4]; 3] {
+    value.into()
+}
@@ -0,0 +1,657 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::cast;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseFloat;
+use quaternion::Quaternion;
+use rotation::{Basis2, Basis3};
+use transform::{Decomposed, NonUniformDecomposed};
+use vector::{Vector2, Vector3, Vector4};
+
+/// The maximum number of sweeps made by the Jacobi methods before giving up on
+/// further convergence.
+const MAX_JACOBI_SWEEPS: usize = 32;
+
+/// An [LU decomposition] with partial pivoting, such that `p * a == l * u`.
+///
+/// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lu<M> {
+    /// The permutation matrix recording the row swaps.
+    pub p: M,
+    /// A lower triangular matrix with ones along the diagonal.
+    pub l: M,
+    /// An upper triangular matrix.
+    pub u: M,
+}
+
+/// A [QR decomposition], such that `a == q * r`.
+///
+/// [QR decomposition]: https://en.wikipedia.org/wiki/QR_decomposition
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Qr<M> {
+    /// An orthogonal matrix.
+    pub q: M,
+    /// An upper triangular matrix.
+    pub r: M,
+}
+
+/// A [Cholesky decomposition] of a symmetric positive-definite matrix, such
+/// that `a == l * l.transpose()`.
+///
+/// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cholesky<M> {
+    /// A lower triangular matrix with a positive diagonal.
+    pub l: M,
+}
+
+/// A [singular value decomposition], such that
+/// `a == u * M::from_diagonal(singular_values) * v.transpose()`.
+///
+/// [singular value decomposition]: https://en.wikipedia.org/wiki/Singular_value_decomposition
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Svd<M, V> {
+    /// An orthogonal matrix, whose columns are the left singular vectors.
+    pub u: M,
+    /// The singular values, which are non-negative and sorted from largest
+    /// to smallest.
+    pub singular_values: V,
+    /// An orthogonal matrix, whose columns are the right singular vectors.
+    pub v: M,
+}
+
+/// The [eigendecomposition] of a symmetric matrix, such that
+/// `a == eigenvectors * M::from_diagonal(eigenvalues) * eigenvectors.transpose()`.
+///
+/// [eigendecomposition]: https://en.wikipedia.org/wiki/Eigendecomposition_of_a_matrix
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SymmetricEigen<B, V> {
+    /// The eigenvalues, sorted from largest to smallest.
+    pub eigenvalues: V,
+    /// The rotation whose columns are the unit eigenvectors, in the same
+    /// order as the eigenvalues. This is a `Basis2` or `Basis3` for `Matrix2`
+    /// and `Matrix3`, and an orthogonal `Matrix4` for `Matrix4`.
+    pub eigenvectors: B,
+}
+
+/// The translation, rotation and per-axis scale of an affine transformation,
+/// as returned by `Matrix4::decompose`, such that
+/// `m == T(translation) * R(rotation) * S(scale)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trs<S> {
+    pub translation: Vector3<S>,
+    /// A unit quaternion.
+    pub rotation: Quaternion<S>,
+    /// The scale along each of the local axes. A reflection is represented by
+    /// a negative `x` scale.
+    pub scale: Vector3<S>,
+}
+
+/// The reason a `Matrix4` could not be decomposed into a `Trs`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecomposeError {
+    /// The bottom row of the matrix is not `(0, 0, 0, 1)`, so it is not an
+    /// affine transformation.
+    NotAffine,
+    /// The matrix scales at least one axis to zero.
+    Singular,
+    /// The matrix shears its axes, so they are not mutually orthogonal.
+    Shear,
+}
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecomposeError::NotAffine => write!(f, "matrix is not an affine transformation"),
+            DecomposeError::Singular => write!(f, "matrix has a zero scale"),
+            DecomposeError::Shear => write!(f, "matrix contains a shear"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for DecomposeError {}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Decompose an affine transformation into its translation, rotation and
+    /// per-axis scale.
+    ///
+    /// If the matrix contains a reflection, it is represented by negating the
+    /// `x` scale, so that the rotation is always proper. Axes are treated as
+    /// sheared if the cosine of the angle between any two of them exceeds the
+    /// square root of the scalar type's epsilon, which tolerates the rounding
+    /// errors found in matrices loaded from files. An axis is only singular
+    /// if its length is exactly zero, so arbitrarily small scales survive.
+    pub fn decompose(&self) -> Result<Trs<S>, DecomposeError> {
+        let affine = ulps_eq!(self.x.w, &S::zero()) && ulps_eq!(self.y.w, &S::zero()) &&
+                     ulps_eq!(self.z.w, &S::zero()) && ulps_eq!(self.w.w, &S::one());
+        if !affine {
+            return Err(DecomposeError::NotAffine);
+        }
+
+        let mut axes = [self.x.truncate(), self.y.truncate(), self.z.truncate()];
+        let mut scale = Vector3::zero();
+        for i in 0..3 {
+            scale[i] = axes[i].magnitude();
+            if scale[i] == S::zero() {
+                return Err(DecomposeError::Singular);
+            }
+            axes[i] /= scale[i];
+        }
+
+        let tolerance = S::epsilon().sqrt();
+        for &(i, j) in [(0, 1), (0, 2), (1, 2)].iter() {
+            if axes[i].dot(axes[j]).abs() > tolerance {
+                return Err(DecomposeError::Shear);
+            }
+        }
+
+        if axes[0].dot(axes[1].cross(axes[2])) < S::zero() {
+            scale.x = -scale.x;
+            axes[0] = -axes[0];
+        }
+
+        let rotation = Quaternion::from(Matrix3::from_cols(axes[0], axes[1], axes[2]));
+        Ok(Trs {
+            translation: self.w.truncate(),
+            rotation: rotation.normalize(),
+            scale,
+        })
+    }
+}
+
+impl<S: BaseFloat> Trs<S> {
+    /// Convert to a `Decomposed`, if the scale is the same along each axis.
+    ///
+    /// A reflection in every axis is represented by a negative uniform scale.
+    pub fn to_decomposed(&self) -> Option<Decomposed<Vector3<S>, Quaternion<S>>> {
+        let s = self.scale;
+        if !relative_eq!(s.x.abs(), s.y) || !relative_eq!(s.y, s.z) {
+            return None;
+        }
+
+        // `R * S(-s, s, s) == (R * S(1, -1, -1)) * -s`
+        let rot = if s.x < S::zero() {
+            self.rotation * Quaternion::new(S::zero(), S::one(), S::zero(), S::zero())
+        } else {
+            self.rotation
+        };
+        Some(Decomposed { scale: s.x, rot, disp: self.translation })
+    }
+}
+
+impl<S: BaseFloat> From<Trs<S>> for Matrix4<S> {
+    fn from(trs: Trs<S>) -> Matrix4<S> {
+        let m = Matrix3::from(trs.rotation);
+        Matrix4::from_cols((m.x * trs.scale.x).extend(S::zero()),
+                           (m.y * trs.scale.y).extend(S::zero()),
+                           (m.z * trs.scale.z).extend(S::zero()),
+                           trs.translation.extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat> From<Trs<S>> for NonUniformDecomposed<Vector3<S>, Quaternion<S>> {
+    #[inline]
+    fn from(trs: Trs<S>) -> NonUniformDecomposed<Vector3<S>, Quaternion<S>> {
+        NonUniformDecomposed {
+            scale: trs.scale,
+            rot: trs.rotation,
+            disp: trs.translation,
+        }
+    }
+}
+
+impl<S: BaseFloat> AbsDiffEq for Trs<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.translation, &other.translation, epsilon) &&
+        Quaternion::abs_diff_eq(&self.rotation, &other.rotation, epsilon) &&
+        Vector3::abs_diff_eq(&self.scale, &other.scale, epsilon)
+    }
+}
+
+impl<S: BaseFloat> RelativeEq for Trs<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.translation, &other.translation, epsilon, max_relative) &&
+        Quaternion::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative) &&
+        Vector3::relative_eq(&self.scale, &other.scale, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> UlpsEq for Trs<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps) &&
+        Quaternion::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps) &&
+        Vector3::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps)
+    }
+}
+
+macro_rules! impl_decompositions {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the LU decomposition of this matrix, using partial
+            /// pivoting. This always succeeds, but `u` has a zero on its
+            /// diagonal if the matrix is singular.
+            pub fn lu(&self) -> Lu<$MatrixN<S>> {
+                let mut p = $MatrixN::identity();
+                let mut l = $MatrixN::identity();
+                let mut u = *self;
+
+                for k in 0..$n {
+                    // Swap the row with the largest pivot into place
+                    let mut pivot = k;
+                    for r in (k + 1)..$n {
+                        if u[k][r].abs() > u[k][pivot].abs() {
+                            pivot = r;
+                        }
+                    }
+                    if pivot != k {
+                        u.swap_rows(k, pivot);
+                        p.swap_rows(k, pivot);
+                        for c in 0..k {
+                            l.swap_elements((c, k), (c, pivot));
+                        }
+                    }
+
+                    if u[k][k] == S::zero() {
+                        // The rest of the column is zero too, so there is
+                        // nothing to eliminate
+                        continue;
+                    }
+
+                    for r in (k + 1)..$n {
+                        let factor = u[k][r] / u[k][k];
+                        l[k][r] = factor;
+                        u[k][r] = S::zero();
+                        for c in (k + 1)..$n {
+                            let delta = factor * u[c][k];
+                            u[c][r] -= delta;
+                        }
+                    }
+                }
+
+                Lu { p, l, u }
+            }
+
+            /// Compute the QR decomposition of this matrix, using Householder
+            /// reflections.
+            pub fn qr(&self) -> Qr<$MatrixN<S>> {
+                let two: S = cast(2).unwrap();
+                let mut q = $MatrixN::identity();
+                let mut r = *self;
+
+                for k in 0..($n - 1) {
+                    // The reflection that zeroes the column below the diagonal
+                    let mut v = $VectorN::zero();
+                    for i in k..$n {
+                        v[i] = r[k][i];
+                    }
+                    let norm = v.magnitude();
+                    if norm == S::zero() {
+                        continue;
+                    }
+                    let shift = if v[k] > S::zero() { norm } else { -norm };
+                    v[k] += shift;
+                    let v_mag2 = v.magnitude2();
+
+                    for c in k..$n {
+                        let f = two * v.dot(r[c]) / v_mag2;
+                        r[c] -= v * f;
+                    }
+                    for i in (k + 1)..$n {
+                        r[k][i] = S::zero();
+                    }
+
+                    for row in 0..$n {
+                        let f = two * v.dot(q.row(row)) / v_mag2;
+                        for i in k..$n {
+                            q[i][row] -= v[i] * f;
+                        }
+                    }
+                }
+
+                Qr { q, r }
+            }
+
+            /// Compute the Cholesky decomposition of this matrix, which must
+            /// be symmetric. Only the lower triangle of the matrix is read.
+            ///
+            /// Returns `None` if the matrix is not positive-definite.
+            pub fn cholesky(&self) -> Option<Cholesky<$MatrixN<S>>> {
+                let mut l = $MatrixN::zero();
+
+                for j in 0..$n {
+                    let mut d = self[j][j];
+                    for k in 0..j {
+                        d -= l[k][j] * l[k][j];
+                    }
+                    if d <= S::zero() {
+                        return None;
+                    }
+                    l[j][j] = d.sqrt();
+
+                    for i in (j + 1)..$n {
+                        let mut s = self[j][i];
+                        for k in 0..j {
+                            s -= l[k][i] * l[k][j];
+                        }
+                        l[j][i] = s / l[j][j];
+                    }
+                }
+
+                Some(Cholesky { l })
+            }
+
+            /// Compute the singular value decomposition of this matrix, using
+            /// the one-sided Jacobi method.
+            pub fn svd(&self) -> Svd<$MatrixN<S>, $VectorN<S>> {
+                let two: S = cast(2).unwrap();
+                let mut u = *self;
+                let mut v = $MatrixN::identity();
+
+                // Rotate pairs of columns until they are all orthogonal
+                for _ in 0..MAX_JACOBI_SWEEPS {
+                    let mut rotated = false;
+                    for i in 0..$n {
+                        for j in (i + 1)..$n {
+                            let alpha = u[i].magnitude2();
+                            let beta = u[j].magnitude2();
+                            let gamma = u[i].dot(u[j]);
+                            if gamma.abs() <= S::epsilon() * (alpha * beta).sqrt() {
+                                continue;
+                            }
+                            rotated = true;
+
+                            let zeta = (beta - alpha) / (two * gamma);
+                            let t = zeta.signum() / (zeta.abs() + (S::one() + zeta * zeta).sqrt());
+                            let c = (S::one() + t * t).sqrt().recip();
+                            let s = c * t;
+
+                            let (ui, uj) = (u[i], u[j]);
+                            u[i] = ui * c - uj * s;
+                            u[j] = ui * s + uj * c;
+                            let (vi, vj) = (v[i], v[j]);
+                            v[i] = vi * c - vj * s;
+                            v[j] = vi * s + vj * c;
+                        }
+                    }
+                    if !rotated {
+                        break;
+                    }
+                }
+
+                // The singular values are the lengths of the columns
+                let mut singular_values = $VectorN::zero();
+                for i in 0..$n {
+                    singular_values[i] = u[i].magnitude();
+                }
+
+                // Sort the singular values from largest to smallest
+                for i in 0..$n {
+                    let mut largest = i;
+                    for j in (i + 1)..$n {
+                        if singular_values[j] > singular_values[largest] {
+                            largest = j;
+                        }
+                    }
+                    if largest != i {
+                        singular_values.swap_elements(i, largest);
+                        u.swap_columns(i, largest);
+                        v.swap_columns(i, largest);
+                    }
+                }
+
+                // Normalize the left singular vectors. Where a singular value is
+                // zero its vector is lost, so choose the axis that is furthest
+                // from the vectors found so far and make it orthogonal to them.
+                let tolerance = singular_values[0] * S::epsilon() * cast($n).unwrap();
+                for i in 0..$n {
+                    if singular_values[i] > tolerance {
+                        u[i] /= singular_values[i];
+                        continue;
+                    }
+
+                    let mut best = $VectorN::zero();
+                    for axis in 0..$n {
+                        let mut candidate = $VectorN::zero();
+                        candidate[axis] = S::one();
+                        for j in 0..i {
+                            candidate -= u[j] * u[j][axis];
+                        }
+                        if candidate.magnitude2() > best.magnitude2() {
+                            best = candidate;
+                        }
+                    }
+                    u[i] = best.normalize();
+                }
+
+                Svd { u, singular_values, v }
+            }
+        }
+
+        impl<S: BaseFloat> Lu<$MatrixN<S>> {
+            /// Solve `a * x == b` for `x`.
+            ///
+            /// Returns `None` if the matrix is singular to working precision.
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                // Solve `l * y == p * b` by forward substitution
+                let mut y = self.p * b;
+                for i in 0..$n {
+                    for j in 0..i {
+                        let delta = self.l[j][i] * y[j];
+                        y[i] -= delta;
+                    }
+                }
+
+                back_substitute!(self.u, y, $n)
+            }
+
+            /// The determinant of the decomposed matrix.
+            pub fn determinant(&self) -> S {
+                self.p.determinant() * self.u.diagonal().product()
+            }
+        }
+
+        impl<S: BaseFloat> Qr<$MatrixN<S>> {
+            /// Solve `a * x == b` for `x`.
+            ///
+            /// Returns `None` if the matrix is singular to working precision.
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                let y = self.q.transpose() * b;
+                back_substitute!(self.r, y, $n)
+            }
+        }
+
+        impl<S: BaseFloat> Cholesky<$MatrixN<S>> {
+            /// Solve `a * x == b` for `x`.
+            pub fn solve(&self, b: $VectorN<S>) -> $VectorN<S> {
+                // Solve `l * y == b` by forward substitution
+                let mut y = b;
+                for i in 0..$n {
+                    for j in 0..i {
+                        let delta = self.l[j][i] * y[j];
+                        y[i] -= delta;
+                    }
+                    y[i] /= self.l[i][i];
+                }
+
+                // Solve `l.transpose() * x == y` by back substitution. The
+                // diagonal of `l` is positive, so there is nothing to check.
+                let mut x = y;
+                for i in (0..$n).rev() {
+                    for j in (i + 1)..$n {
+                        let delta = self.l[i][j] * x[j];
+                        x[i] -= delta;
+                    }
+                    x[i] /= self.l[i][i];
+                }
+                x
+            }
+        }
+    }
+}
+
+macro_rules! impl_symmetric_eigen {
+    ($MatrixN:ident, $VectorN:ident, $Eigenvectors:ty, $n:expr, $from_matrix:path) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the eigenvalues and eigenvectors of this matrix, which
+            /// must be symmetric, using the cyclic Jacobi method.
+            ///
+            /// The eigenvectors form a right-handed orthonormal basis. Where
+            /// eigenvalues are repeated, any orthonormal basis of their
+            /// eigenspace may be returned.
+            pub fn symmetric_eigen(&self) -> SymmetricEigen<$Eigenvectors, $VectorN<S>> {
+                let two: S = cast(2).unwrap();
+                let mut a = *self;
+                let mut v = $MatrixN::identity();
+
+                // Rotate away the off-diagonal elements until they vanish
+                for _ in 0..MAX_JACOBI_SWEEPS {
+                    let mut rotated = false;
+                    for p in 0..$n {
+                        for q in (p + 1)..$n {
+                            let apq = a[q][p];
+                            if apq.abs() <= S::epsilon() * (a[p][p].abs() + a[q][q].abs()) {
+                                continue;
+                            }
+                            rotated = true;
+
+                            let theta = (a[q][q] - a[p][p]) / (two * apq);
+                            let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+                            let c = (t * t + S::one()).sqrt().recip();
+                            let s = t * c;
+
+                            // `a = j.transpose() * a * j`, and `v = v * j`
+                            let (ap, aq) = (a[p], a[q]);
+                            a[p] = ap * c - aq * s;
+                            a[q] = ap * s + aq * c;
+                            for k in 0..$n {
+                                let (akp, akq) = (a[k][p], a[k][q]);
+                                a[k][p] = akp * c - akq * s;
+                                a[k][q] = akp * s + akq * c;
+                            }
+                            a[q][p] = S::zero();
+                            a[p][q] = S::zero();
+
+                            let (vp, vq) = (v[p], v[q]);
+                            v[p] = vp * c - vq * s;
+                            v[q] = vp * s + vq * c;
+                        }
+                    }
+                    if !rotated {
+                        break;
+                    }
+                }
+
+                // Sort the eigenvalues from largest to smallest
+                let mut eigenvalues = a.diagonal();
+                for i in 0..$n {
+                    let mut largest = i;
+                    for j in (i + 1)..$n {
+                        if eigenvalues[j] > eigenvalues[largest] {
+                            largest = j;
+                        }
+                    }
+                    if largest != i {
+                        eigenvalues.swap_elements(i, largest);
+                        v.swap_columns(i, largest);
+                    }
+                }
+
+                // Flip the last eigenvector if needed to make the basis a rotation
+                if v.determinant() < S::zero() {
+                    v[$n - 1] = -v[$n - 1];
+                }
+
+                SymmetricEigen {
+                    eigenvalues,
+                    eigenvectors: $from_matrix(v),
+                }
+            }
+        }
+    }
+}
+
+/// Solve `$u * x == $y` for `x`, where `$u` is upper triangular, returning
+/// `None` if a pivot on the diagonal of `$u` is negligible compared to the
+/// largest one.
+macro_rules! back_substitute {
+    ($u:expr, $y:expr, $n:expr) => {{
+        let u = $u;
+        let mut x = $y;
+
+        let mut largest = S::zero();
+        for i in 0..$n {
+            largest = largest.max(u[i][i].abs());
+        }
+        let tolerance = largest * S::epsilon() * cast($n).unwrap();
+
+        let mut singular = false;
+        for i in (0..$n).rev() {
+            if u[i][i].abs() <= tolerance {
+                singular = true;
+                break;
+            }
+            for j in (i + 1)..$n {
+                let delta = u[j][i] * x[j];
+                x[i] -= delta;
+            }
+            x[i] /= u[i][i];
+        }
+        if singular { None } else { Some(x) }
+    }}
+}
+
+impl_decompositions!(Matrix2, Vector2, 2);
+impl_decompositions!(Matrix3, Vector3, 3);
+impl_decompositions!(Matrix4, Vector4, 4);
+
+impl_symmetric_eigen!(Matrix2, Vector2, Basis2<S>, 2, Basis2::from_matrix_unchecked);
+impl_symmetric_eigen!(Matrix3, Vector3, Basis3<S>, 3, Basis3::from_matrix_unchecked);
+impl_symmetric_eigen!(Matrix4, Vector4, Matrix4<S>, 4, Matrix4::from);
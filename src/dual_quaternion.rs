@@ -0,0 +1,321 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use isometry::Isometry3;
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::Rotation;
+use transform::{Decomposed, Transform, Transform3};
+use vector::Vector3;
+
+/// A dual quaternion, representing a rigid transformation in three
+/// dimensions.
+///
+/// A unit dual quaternion `real + ε dual` encodes the rotation `real` followed
+/// by the translation `t`, where `dual = ½ t real`. Unlike `Decomposed`, dual
+/// quaternions can be blended linearly without introducing scale or shear,
+/// which makes them well suited to skinning.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DualQuaternion<S> {
+    /// The rotational part.
+    pub real: Quaternion<S>,
+    /// The dual part, which encodes the translation.
+    pub dual: Quaternion<S>,
+}
+
+impl<S: BaseFloat> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub fn new(real: Quaternion<S>, dual: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion { real, dual }
+    }
+
+    /// Construct a dual quaternion that rotates by `rotation` and then
+    /// translates by `translation`. The rotation should be a unit quaternion.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quaternion<S>, translation: Vector3<S>) -> DualQuaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let dual = Quaternion::from_sv(S::zero(), translation * half) * rotation;
+        DualQuaternion::new(rotation, dual)
+    }
+
+    /// A dual quaternion that only rotates.
+    #[inline]
+    pub fn from_rotation(rotation: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion::new(rotation, Quaternion::from_sv(S::zero(), Vector3::zero()))
+    }
+
+    /// A dual quaternion that only translates.
+    #[inline]
+    pub fn from_translation(translation: Vector3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(Quaternion::one(), translation)
+    }
+
+    /// Construct a dual quaternion from a decomposed transformation, returning
+    /// `None` if its scale is not one.
+    #[inline]
+    pub fn from_decomposed(dec: Decomposed<Vector3<S>, Quaternion<S>>) -> Option<DualQuaternion<S>> {
+        if relative_eq!(dec.scale, S::one()) {
+            Some(DualQuaternion::from_rotation_translation(dec.rot, dec.disp))
+        } else {
+            None
+        }
+    }
+
+    /// The rotational part of this dual quaternion.
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<S> {
+        self.real
+    }
+
+    /// The translation encoded by this dual quaternion, which must be
+    /// normalized.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        (self.dual * self.real.conjugate()).v * cast(2.0f64).unwrap()
+    }
+
+    /// The quaternion conjugate of both parts. For a unit dual quaternion this
+    /// is the inverse transformation.
+    #[inline]
+    pub fn conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// The dual number conjugate, which negates the dual part.
+    #[inline]
+    pub fn dual_conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real, -self.dual)
+    }
+
+    /// The combination of the quaternion and dual number conjugates, which is
+    /// used to transform points in the form `q (1 + ε p) q**`.
+    #[inline]
+    pub fn combined_conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), -self.dual.conjugate())
+    }
+
+    /// Scale this dual quaternion to unit length, and remove any component of
+    /// the dual part that is not orthogonal to the real part.
+    ///
+    /// The real part must be non-zero.
+    #[inline]
+    pub fn normalize(self) -> DualQuaternion<S> {
+        let recip = self.real.magnitude().recip();
+        let real = self.real * recip;
+        let dual = self.dual * recip;
+        DualQuaternion::new(real, dual - real * real.dot(dual))
+    }
+
+    /// Raise this unit dual quaternion to the power `t`, scaling both the
+    /// angle and the displacement of its screw motion.
+    pub fn powf(self, t: S) -> DualQuaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let sin_half_angle = self.real.v.magnitude();
+
+        // A pure translation has no screw axis
+        if ulps_eq!(sin_half_angle, &S::zero()) {
+            return DualQuaternion::from_translation(self.translation() * t);
+        }
+
+        let half_angle = sin_half_angle.atan2(self.real.s);
+        let axis = self.real.v / sin_half_angle;
+        let pitch = -(self.dual.s + self.dual.s) / sin_half_angle;
+        let moment = (self.dual.v - axis * (pitch * half * self.real.s)) / sin_half_angle;
+
+        let half_angle = half_angle * t;
+        let half_pitch = pitch * t * half;
+        let (s, c) = half_angle.sin_cos();
+        DualQuaternion::new(Quaternion::from_sv(c, axis * s),
+                            Quaternion::from_sv(-half_pitch * s, moment * s + axis * (half_pitch * c)))
+    }
+
+    /// Screw linear interpolation (ScLERP) between two unit dual quaternions.
+    ///
+    /// This follows the shortest screw motion from `self` to `other`, with
+    /// constant angular and linear velocity.
+    pub fn sclerp(self, other: DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        let other = if self.real.dot(other.real) < S::zero() { -other } else { other };
+        self * (self.conjugate() * other).powf(amount)
+    }
+
+    /// Dual quaternion linear blending (DLB) of unit dual quaternions, as used
+    /// for skinning.
+    ///
+    /// Each dual quaternion is flipped into the hemisphere of the first before
+    /// the weighted sum is normalized. Returns `None` if there are no dual
+    /// quaternions or the weighted sum is degenerate.
+    pub fn blend<I>(weighted: I) -> Option<DualQuaternion<S>>
+        where I: IntoIterator<Item = (DualQuaternion<S>, S)>
+    {
+        let mut iter = weighted.into_iter();
+        let (first, weight) = iter.next()?;
+        let mut sum = first * weight;
+        for (dq, weight) in iter {
+            let weight = if first.real.dot(dq.real) < S::zero() { -weight } else { weight };
+            sum = sum + dq * weight;
+        }
+
+        if ulps_eq!(sum.real.magnitude2(), &S::zero()) {
+            None
+        } else {
+            Some(sum.normalize())
+        }
+    }
+}
+
+impl_operator!(<S: BaseFloat> Neg for DualQuaternion<S> {
+    fn neg(dq) -> DualQuaternion<S> { DualQuaternion::new(-dq.real, -dq.dual) }
+});
+
+impl_operator!(<S: BaseFloat> Mul<S> for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs, lhs.dual * rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Add<DualQuaternion<S> > for DualQuaternion<S> {
+    fn add(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real + rhs.real, lhs.dual + rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Sub<DualQuaternion<S> > for DualQuaternion<S> {
+    fn sub(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real - rhs.real, lhs.dual - rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<DualQuaternion<S> > for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs.real, lhs.real * rhs.dual + lhs.dual * rhs.real)
+    }
+});
+
+impl<S: BaseFloat> Transform<Point3<S>> for DualQuaternion<S> {
+    #[inline]
+    fn one() -> DualQuaternion<S> {
+        DualQuaternion::from_rotation(Quaternion::one())
+    }
+
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rotation = Quaternion::look_at(center - eye, up);
+        let translation = rotation.rotate_vector(Point3::origin() - eye);
+        DualQuaternion::from_rotation_translation(rotation, translation)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.real.rotate_vector(vec)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Point3::from_vec(self.real.rotate_vector(point.to_vec()) + self.translation())
+    }
+
+    #[inline]
+    fn concat(&self, other: &DualQuaternion<S>) -> DualQuaternion<S> {
+        self * other
+    }
+
+    #[inline]
+    fn inverse_transform(&self) -> Option<DualQuaternion<S>> {
+        if ulps_eq!(self.real.magnitude2(), &S::zero()) {
+            None
+        } else {
+            Some(self.normalize().conjugate())
+        }
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for DualQuaternion<S> {}
+
+impl<S: BaseFloat> From<Isometry3<S>> for DualQuaternion<S> {
+    #[inline]
+    fn from(iso: Isometry3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(iso.rotation, iso.translation)
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Isometry3<S> {
+    #[inline]
+    fn from(dq: DualQuaternion<S>) -> Isometry3<S> {
+        Isometry3::new(dq.real, dq.translation())
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Matrix4<S> {
+    /// Convert a unit dual quaternion to the equivalent homogeneous matrix.
+    fn from(dq: DualQuaternion<S>) -> Matrix4<S> {
+        let m = Matrix3::from(dq.real);
+        Matrix4::from_cols(m.x.extend(S::zero()),
+                           m.y.extend(S::zero()),
+                           m.z.extend(S::zero()),
+                           dq.translation().extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat> AbsDiffEq for DualQuaternion<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Quaternion::abs_diff_eq(&self.real, &other.real, epsilon) &&
+        Quaternion::abs_diff_eq(&self.dual, &other.dual, epsilon)
+    }
+}
+
+impl<S: BaseFloat> RelativeEq for DualQuaternion<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Quaternion::relative_eq(&self.real, &other.real, epsilon, max_relative) &&
+        Quaternion::relative_eq(&self.dual, &other.dual, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> UlpsEq for DualQuaternion<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Quaternion::ulps_eq(&self.real, &other.real, epsilon, max_ulps) &&
+        Quaternion::ulps_eq(&self.dual, &other.dual, epsilon, max_ulps)
+    }
+}
@@ -13,14 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::cast;
 
 use structure::*;
 
 use angle::Rad;
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use matrix::{Matrix3, Matrix4};
 use quaternion::Quaternion;
+use rotation::{Basis3, Rotation3};
 #[cfg(feature = "mint")]
 use mint;
 use num::BaseFloat;
@@ -33,6 +36,8 @@ use num::BaseFloat;
 /// the X axis, then the Y axis, and lastly the Z axis (using intrinsic
 /// rotations). Since all three rotation axes are used, the angles are
 /// Tait–Bryan angles rather than proper Euler angles.
+/// Other sequences, and extrinsic rotations, are supported by
+/// [`EulerAngles`](struct.EulerAngles.html).
 ///
 /// # Ranges
 ///
@@ -142,7 +147,7 @@ impl<S: BaseFloat> From<Quaternion<S>> for Euler<Rad<S>> {
     }
 }
 
-impl<A: Angle> ApproxEq for Euler<A> {
+impl<A: Angle> AbsDiffEq for Euler<A> {
     type Epsilon = A::Epsilon;
 
     #[inline]
@@ -151,13 +156,17 @@ impl<A: Angle> ApproxEq for Euler<A> {
     }
 
     #[inline]
-    fn default_max_relative() -> A::Epsilon {
-        A::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: A::Epsilon) -> bool {
+        A::abs_diff_eq(&self.x, &other.x, epsilon) &&
+        A::abs_diff_eq(&self.y, &other.y, epsilon) &&
+        A::abs_diff_eq(&self.z, &other.z, epsilon)
     }
+}
 
+impl<A: Angle> RelativeEq for Euler<A> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        A::default_max_ulps()
+    fn default_max_relative() -> A::Epsilon {
+        A::default_max_relative()
     }
 
     #[inline]
@@ -166,6 +175,13 @@ impl<A: Angle> ApproxEq for Euler<A> {
         A::relative_eq(&self.y, &other.y, epsilon, max_relative) &&
         A::relative_eq(&self.z, &other.z, epsilon, max_relative)
     }
+}
+
+impl<A: Angle> UlpsEq for Euler<A> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        A::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: A::Epsilon, max_ulps: u32) -> bool {
@@ -175,6 +191,7 @@ impl<A: Angle> ApproxEq for Euler<A> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<A: Angle + Rand> Rand for Euler<A> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Euler<A> {
@@ -182,6 +199,313 @@ impl<A: Angle + Rand> Rand for Euler<A> {
     }
 }
 
+/// The sequence of axes that a set of `EulerAngles` rotates around.
+///
+/// The first six sequences use all three axes, giving Tait–Bryan angles. The
+/// last six repeat the first axis, giving proper Euler angles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerSequence {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerSequence {
+    /// The indices of the three axes in the sequence, in the order they are
+    /// listed.
+    #[inline]
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerSequence::XYZ => (0, 1, 2),
+            EulerSequence::XZY => (0, 2, 1),
+            EulerSequence::YXZ => (1, 0, 2),
+            EulerSequence::YZX => (1, 2, 0),
+            EulerSequence::ZXY => (2, 0, 1),
+            EulerSequence::ZYX => (2, 1, 0),
+            EulerSequence::XYX => (0, 1, 0),
+            EulerSequence::XZX => (0, 2, 0),
+            EulerSequence::YXY => (1, 0, 1),
+            EulerSequence::YZY => (1, 2, 1),
+            EulerSequence::ZXZ => (2, 0, 2),
+            EulerSequence::ZYZ => (2, 1, 2),
+        }
+    }
+}
+
+/// Whether the axes of a set of `EulerAngles` move with the rotated body or
+/// stay fixed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerFrame {
+    /// Each rotation is around an axis of the body, as rotated by the
+    /// previous rotations.
+    Intrinsic,
+    /// Each rotation is around an axis of the fixed, world frame.
+    Extrinsic,
+}
+
+/// A set of [Euler angles] in any of the twelve axis sequences, applied
+/// either intrinsically or extrinsically.
+///
+/// The angle `a` is applied around the first axis of `sequence`, `b` around
+/// the second and `c` around the third. An intrinsic rotation is equivalent
+/// to the extrinsic rotation with the reversed sequence and angles, so that
+/// intrinsic `XYZ` is the same rotation as extrinsic `ZYX`. `Euler` is the
+/// intrinsic `XYZ` case.
+///
+/// # Ranges
+///
+/// The angles extracted by `from_matrix3` and `from_quaternion` are in the
+/// following ranges:
+///
+/// - Tait–Bryan sequences: `a` and `c` in [-pi, pi], `b` in [-pi/2, pi/2]
+/// - proper Euler sequences: `a` and `c` in [-pi, pi], `b` in [0, pi]
+///
+/// # Example
+///
+/// Converting yaw, pitch and roll angles, as used in aerospace, to a
+/// quaternion:
+///
+/// ```
+/// use cgmath::{Deg, EulerAngles, EulerSequence, Quaternion};
+///
+/// let (yaw, pitch, roll) = (Deg(30.0), Deg(10.0), Deg(-5.0));
+/// let rotation = Quaternion::from(EulerAngles::intrinsic(EulerSequence::ZYX, yaw, pitch, roll));
+/// ```
+///
+/// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EulerAngles<A: Angle> {
+    /// The angle to apply around the first axis of the sequence.
+    pub a: A,
+    /// The angle to apply around the second axis of the sequence.
+    pub b: A,
+    /// The angle to apply around the third axis of the sequence.
+    pub c: A,
+    /// The axes to rotate around.
+    pub sequence: EulerSequence,
+    /// Whether the axes move with the body or stay fixed.
+    pub frame: EulerFrame,
+}
+
+impl<A: Angle> EulerAngles<A> {
+    /// Construct a set of euler angles.
+    pub fn new(sequence: EulerSequence, frame: EulerFrame, a: A, b: A, c: A) -> EulerAngles<A> {
+        EulerAngles { a, b, c, sequence, frame }
+    }
+
+    /// Construct a set of euler angles, rotating around the axes of the body.
+    pub fn intrinsic(sequence: EulerSequence, a: A, b: A, c: A) -> EulerAngles<A> {
+        EulerAngles::new(sequence, EulerFrame::Intrinsic, a, b, c)
+    }
+
+    /// Construct a set of euler angles, rotating around the fixed axes of
+    /// the world.
+    pub fn extrinsic(sequence: EulerSequence, a: A, b: A, c: A) -> EulerAngles<A> {
+        EulerAngles::new(sequence, EulerFrame::Extrinsic, a, b, c)
+    }
+}
+
+impl<S: BaseFloat> EulerAngles<Rad<S>> {
+    /// Extract the angles of a rotation matrix, in the given sequence and
+    /// frame.
+    ///
+    /// When the rotation is in [gimbal lock], the first and third axes line
+    /// up and only their combined angle can be recovered. In that case the
+    /// third angle is set to zero, and the first angle holds the whole
+    /// rotation around the shared axis.
+    ///
+    /// [gimbal lock]: https://en.wikipedia.org/wiki/Gimbal_lock
+    pub fn from_matrix3(m: Matrix3<S>, sequence: EulerSequence, frame: EulerFrame) -> EulerAngles<Rad<S>> {
+        let (i, j, k) = sequence.axes();
+        match frame {
+            EulerFrame::Intrinsic => {
+                let (a, b, c, _) = intrinsic_angles(&m, i, j, k);
+                EulerAngles::new(sequence, frame, a, b, c)
+            },
+            EulerFrame::Extrinsic => {
+                let (c, b, a, locked) = intrinsic_angles(&m, k, j, i);
+                if !locked {
+                    return EulerAngles::new(sequence, frame, a, b, c);
+                }
+
+                // The intrinsic angles put the whole locked rotation in `c`.
+                // Undo the rotation around the second axis to find it as a
+                // rotation around the first axis instead.
+                let second: Basis3<S> = axis_rotation(j, -b);
+                let rest = Matrix3::from(second) * m;
+                let (p, q) = ((i + 1) % 3, (i + 2) % 3);
+                let a = Rad::atan2(rest[p][q], rest[p][p]);
+                EulerAngles::new(sequence, frame, a, b, Rad::zero())
+            },
+        }
+    }
+
+    /// Extract the angles of a quaternion, in the given sequence and frame.
+    /// See `from_matrix3` for the treatment of gimbal lock.
+    pub fn from_quaternion(q: Quaternion<S>, sequence: EulerSequence, frame: EulerFrame) -> EulerAngles<Rad<S>> {
+        EulerAngles::from_matrix3(Matrix3::from(q), sequence, frame)
+    }
+}
+
+/// Extract the angles of the intrinsic rotation `R_i(a) * R_j(b) * R_k(c)`,
+/// along with whether the rotation is in gimbal lock.
+fn intrinsic_angles<S: BaseFloat>(m: &Matrix3<S>, i: usize, j: usize, k: usize) -> (Rad<S>, Rad<S>, Rad<S>, bool) {
+    // Relabel the axes so that the sequence becomes `XYZ` or `XYX`. This
+    // conjugates the matrix by a permutation, which reverses the direction of
+    // the rotations if the permutation is odd.
+    let l = 3 - i - j;
+    let perm = [i, j, l];
+    let r = |row: usize, col: usize| m[perm[col]][perm[row]];
+    let sign = if j == (i + 1) % 3 { S::one() } else { -S::one() };
+
+    let threshold = S::epsilon().sqrt();
+    if i != k {
+        // Tait–Bryan angles, locked when the second angle is at +/-90 degrees
+        let cos_b = (r(0, 0) * r(0, 0) + r(0, 1) * r(0, 1)).sqrt();
+        let b = Rad::atan2(r(0, 2), cos_b) * sign;
+        if cos_b < threshold {
+            (Rad::atan2(r(2, 1), r(1, 1)) * sign, b, Rad::zero(), true)
+        } else {
+            (Rad::atan2(-r(1, 2), r(2, 2)) * sign, b, Rad::atan2(-r(0, 1), r(0, 0)) * sign, false)
+        }
+    } else {
+        // Proper Euler angles, locked when the second angle is 0 or 180
+        // degrees. Flipping the signs here keeps the second angle positive
+        // once the direction of the rotations has been restored.
+        let sin_b = (r(0, 1) * r(0, 1) + r(0, 2) * r(0, 2)).sqrt();
+        let b = Rad::atan2(sign * sin_b, r(0, 0)) * sign;
+        if sin_b < threshold {
+            (Rad::atan2(r(2, 1), r(1, 1)) * sign, b, Rad::zero(), true)
+        } else {
+            (Rad::atan2(sign * r(1, 0), -sign * r(2, 0)) * sign, b,
+             Rad::atan2(sign * r(0, 1), sign * r(0, 2)) * sign, false)
+        }
+    }
+}
+
+/// A rotation of `angle` around the axis with index `axis`.
+fn axis_rotation<S: BaseFloat, R: Rotation3<S>>(axis: usize, angle: Rad<S>) -> R {
+    match axis {
+        0 => R::from_angle_x(angle),
+        1 => R::from_angle_y(angle),
+        _ => R::from_angle_z(angle),
+    }
+}
+
+impl<A> From<EulerAngles<A>> for Quaternion<A::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Quaternion<A::Unitless> {
+        let (i, j, k) = src.sequence.axes();
+        let qa: Quaternion<_> = axis_rotation(i, src.a.into());
+        let qb: Quaternion<_> = axis_rotation(j, src.b.into());
+        let qc: Quaternion<_> = axis_rotation(k, src.c.into());
+
+        match src.frame {
+            EulerFrame::Intrinsic => qa * qb * qc,
+            EulerFrame::Extrinsic => qc * qb * qa,
+        }
+    }
+}
+
+impl<A> From<EulerAngles<A>> for Matrix3<A::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Matrix3<A::Unitless> {
+        let (i, j, k) = src.sequence.axes();
+        let ma: Basis3<_> = axis_rotation(i, src.a.into());
+        let mb: Basis3<_> = axis_rotation(j, src.b.into());
+        let mc: Basis3<_> = axis_rotation(k, src.c.into());
+        let (ma, mb, mc) = (Matrix3::from(ma), Matrix3::from(mb), Matrix3::from(mc));
+
+        match src.frame {
+            EulerFrame::Intrinsic => ma * mb * mc,
+            EulerFrame::Extrinsic => mc * mb * ma,
+        }
+    }
+}
+
+impl<A> From<EulerAngles<A>> for Matrix4<A::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Matrix4<A::Unitless> {
+        Matrix3::from(src).into()
+    }
+}
+
+impl<A> From<EulerAngles<A>> for Basis3<A::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Basis3<A::Unitless> {
+        Basis3::from_quaternion(&Quaternion::from(src))
+    }
+}
+
+impl<A: Angle> From<Euler<A>> for EulerAngles<A> {
+    fn from(src: Euler<A>) -> EulerAngles<A> {
+        EulerAngles::intrinsic(EulerSequence::XYZ, src.x, src.y, src.z)
+    }
+}
+
+impl<A: Angle> AbsDiffEq for EulerAngles<A> {
+    type Epsilon = A::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> A::Epsilon {
+        A::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: A::Epsilon) -> bool {
+        self.sequence == other.sequence && self.frame == other.frame &&
+        A::abs_diff_eq(&self.a, &other.a, epsilon) &&
+        A::abs_diff_eq(&self.b, &other.b, epsilon) &&
+        A::abs_diff_eq(&self.c, &other.c, epsilon)
+    }
+}
+
+impl<A: Angle> RelativeEq for EulerAngles<A> {
+    #[inline]
+    fn default_max_relative() -> A::Epsilon {
+        A::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        self.sequence == other.sequence && self.frame == other.frame &&
+        A::relative_eq(&self.a, &other.a, epsilon, max_relative) &&
+        A::relative_eq(&self.b, &other.b, epsilon, max_relative) &&
+        A::relative_eq(&self.c, &other.c, epsilon, max_relative)
+    }
+}
+
+impl<A: Angle> UlpsEq for EulerAngles<A> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        A::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        self.sequence == other.sequence && self.frame == other.frame &&
+        A::ulps_eq(&self.a, &other.a, epsilon, max_ulps) &&
+        A::ulps_eq(&self.b, &other.b, epsilon, max_ulps) &&
+        A::ulps_eq(&self.c, &other.c, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "mint")]
 type MintEuler<S> = mint::EulerAngles<S, mint::IntraXYZ>;
 
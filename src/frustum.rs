@@ -0,0 +1,223 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use aabb::Aabb3;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use matrix::Matrix4;
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use projection::{Convention, DepthRange};
+use sphere::Sphere;
+use vector::{Vector3, Vector4};
+
+/// The result of testing a bounding volume against a `Frustum`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Relation {
+    /// The volume lies entirely inside the frustum.
+    Inside,
+    /// The volume lies entirely outside the frustum.
+    Outside,
+    /// The volume straddles at least one of the planes of the frustum.
+    Intersecting,
+}
+
+/// A view frustum, bounded by six planes whose normals point inwards.
+///
+/// A point is inside the frustum when its signed distance to every plane is
+/// positive or zero.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Frustum<S> {
+    /// The left plane, with points to its right counting as inside.
+    pub left: Plane<S>,
+    /// The right plane, with points to its left counting as inside.
+    pub right: Plane<S>,
+    /// The bottom plane, with points above it counting as inside.
+    pub bottom: Plane<S>,
+    /// The top plane, with points below it counting as inside.
+    pub top: Plane<S>,
+    /// The near plane, with points beyond it counting as inside.
+    pub near: Plane<S>,
+    /// The far plane, with points in front of it counting as inside.
+    pub far: Plane<S>,
+}
+
+impl<S: BaseFloat> Frustum<S> {
+    /// Construct a frustum from its six bounding planes.
+    #[inline]
+    pub fn new(left: Plane<S>, right: Plane<S>,
+               bottom: Plane<S>, top: Plane<S>,
+               near: Plane<S>, far: Plane<S>) -> Frustum<S> {
+        Frustum { left, right, bottom, top, near, far }
+    }
+
+    /// Extract the planes of the frustum from a projection matrix, using the
+    /// method described by [Gribb and Hartmann].
+    ///
+    /// The matrix is expected to map into OpenGL style clip space, where the
+    /// visible volume satisfies `-w <= x, y, z <= w`, as produced by
+    /// `perspective`, `frustum`, `ortho`, `PerspectiveFov`, `Perspective` and
+    /// `Ortho`. For projections with an infinite far plane, such as
+    /// `perspective_infinite`, the far plane has a zero normal and contains
+    /// every point. Matrices with reversed depth, such as those made by
+    /// `perspective_reverse_z`, must use `from_matrix4_in` with a
+    /// `Convention::reverse_z` convention instead. If `proj`
+    /// is a combined `projection * view` matrix, the planes are in world
+    /// space; otherwise they are in view space.
+    ///
+    /// [Gribb and Hartmann]: http://www.cs.otago.ac.nz/postgrads/alexis/planeExtraction.pdf
+    #[inline]
+    pub fn from_matrix4(proj: Matrix4<S>) -> Frustum<S> {
+        Frustum::from_matrix4_in(proj, Convention::opengl())
+    }
+
+    /// Extract the planes of the frustum from a projection matrix that maps
+    /// into the clip space of `convention`, such as one built with
+    /// `Convention::perspective`.
+    pub fn from_matrix4_in(proj: Matrix4<S>, convention: Convention) -> Frustum<S> {
+        let (r0, r1, r2, r3) = (proj.row(0), proj.row(1), proj.row(2), proj.row(3));
+
+        let (near, far) = match convention.depth {
+            DepthRange::NegativeOneToOne => (r3 + r2, r3 - r2),
+            DepthRange::ZeroToOne => (r2, r3 - r2),
+            DepthRange::OneToZero => (r3 - r2, r2),
+        };
+        let (bottom, top) = if convention.flip_y { (r3 - r1, r3 + r1) } else { (r3 + r1, r3 - r1) };
+
+        Frustum::new(plane(r3 + r0), plane(r3 - r0),
+                     plane(bottom), plane(top),
+                     plane(near), plane(far))
+    }
+
+    /// The planes of the frustum, in the order left, right, bottom, top, near
+    /// and far.
+    #[inline]
+    pub fn planes(&self) -> [Plane<S>; 6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
+    }
+
+    /// Test whether `point` lies inside the frustum. Points lying exactly on
+    /// one of the planes are considered to be inside.
+    pub fn contains_point(&self, point: Point3<S>) -> Relation {
+        for plane in self.planes().iter() {
+            if plane.signed_distance(point) < S::zero() {
+                return Relation::Outside;
+            }
+        }
+        Relation::Inside
+    }
+
+    /// Test `sphere` against the frustum.
+    pub fn contains_sphere(&self, sphere: &Sphere<S>) -> Relation {
+        let mut relation = Relation::Inside;
+        for plane in self.planes().iter() {
+            let distance = plane.signed_distance(sphere.center);
+            if distance < -sphere.radius {
+                return Relation::Outside;
+            } else if distance < sphere.radius {
+                relation = Relation::Intersecting;
+            }
+        }
+        relation
+    }
+
+    /// Test `aabb` against the frustum.
+    ///
+    /// This test is conservative: a box lying close to a corner or edge of the
+    /// frustum, but outside of it, may be reported as `Intersecting`.
+    pub fn contains_aabb(&self, aabb: &Aabb3<S>) -> Relation {
+        let mut relation = Relation::Inside;
+        for plane in self.planes().iter() {
+            // The corners of the box furthest along and against the normal
+            let mut positive = aabb.min;
+            let mut negative = aabb.max;
+            for i in 0..3 {
+                if plane.normal[i] >= S::zero() {
+                    positive[i] = aabb.max[i];
+                    negative[i] = aabb.min[i];
+                }
+            }
+
+            if plane.signed_distance(positive) < S::zero() {
+                return Relation::Outside;
+            } else if plane.signed_distance(negative) < S::zero() {
+                relation = Relation::Intersecting;
+            }
+        }
+        relation
+    }
+}
+
+impl<S: BaseFloat> From<Matrix4<S>> for Frustum<S> {
+    #[inline]
+    fn from(proj: Matrix4<S>) -> Frustum<S> {
+        Frustum::from_matrix4(proj)
+    }
+}
+
+fn plane<S: BaseFloat>(v: Vector4<S>) -> Plane<S> {
+    let plane = Plane::from_abcd(v.x, v.y, v.z, v.w);
+    if plane.normal.is_zero() {
+        // The far plane of an infinite projection, which everything is in front of
+        Plane::new(Vector3::zero(), S::neg_infinity())
+    } else {
+        plane.normalize()
+    }
+}
+
+impl<S: BaseFloat> AbsDiffEq for Frustum<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        self.planes().iter().zip(other.planes().iter())
+            .all(|(a, b)| Plane::abs_diff_eq(a, b, epsilon))
+    }
+}
+
+impl<S: BaseFloat> RelativeEq for Frustum<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        self.planes().iter().zip(other.planes().iter())
+            .all(|(a, b)| Plane::relative_eq(a, b, epsilon, max_relative))
+    }
+}
+
+impl<S: BaseFloat> UlpsEq for Frustum<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        self.planes().iter().zip(other.planes().iter())
+            .all(|(a, b)| Plane::ulps_eq(a, b, epsilon, max_ulps))
+    }
+}
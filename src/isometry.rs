@@ -0,0 +1,410 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use angle::Rad;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use rotation::{Basis2, Rotation, Rotation2};
+use transform::{Decomposed, Transform, Transform2, Transform3};
+use vector::{Vector2, Vector3};
+
+/// A rigid body transformation in two dimensions, consisting of a rotation
+/// followed by a translation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Isometry2<S> {
+    pub rotation: Basis2<S>,
+    pub translation: Vector2<S>,
+}
+
+/// A rigid body transformation in three dimensions, consisting of a rotation
+/// followed by a translation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Isometry3<S> {
+    /// A unit quaternion.
+    pub rotation: Quaternion<S>,
+    pub translation: Vector3<S>,
+}
+
+/// A transformation in two dimensions consisting of a uniform scale, followed
+/// by a rotation and a translation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Similarity2<S> {
+    pub scale: S,
+    pub rotation: Basis2<S>,
+    pub translation: Vector2<S>,
+}
+
+/// A transformation in three dimensions consisting of a uniform scale,
+/// followed by a rotation and a translation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Similarity3<S> {
+    pub scale: S,
+    /// A unit quaternion.
+    pub rotation: Quaternion<S>,
+    pub translation: Vector3<S>,
+}
+
+macro_rules! impl_isometry {
+    ($IsometryN:ident, $SimilarityN:ident, $PointN:ident, $VectorN:ident,
+     $HomogeneousN:ident, $Rotation:ident, $TransformN:ident) => {
+        impl<S: BaseFloat> $IsometryN<S> {
+            /// Construct an isometry from a rotation and a translation.
+            #[inline]
+            pub fn new(rotation: $Rotation<S>, translation: $VectorN<S>) -> $IsometryN<S> {
+                $IsometryN { rotation, translation }
+            }
+
+            /// An isometry that only translates.
+            #[inline]
+            pub fn from_translation(translation: $VectorN<S>) -> $IsometryN<S> {
+                $IsometryN::new($Rotation::one(), translation)
+            }
+
+            /// An isometry that only rotates.
+            #[inline]
+            pub fn from_rotation(rotation: $Rotation<S>) -> $IsometryN<S> {
+                $IsometryN::new(rotation, $VectorN::zero())
+            }
+
+            /// Convert a `Decomposed`, returning `None` unless its scale is one.
+            #[inline]
+            pub fn from_decomposed(dec: Decomposed<$VectorN<S>, $Rotation<S>>) -> Option<$IsometryN<S>> {
+                $SimilarityN::from(dec).to_isometry()
+            }
+
+            /// The inverse of this isometry, which always exists.
+            #[inline]
+            pub fn inverse(&self) -> $IsometryN<S> {
+                let rotation = self.rotation.invert();
+                $IsometryN::new(rotation, -rotation.rotate_vector(self.translation))
+            }
+        }
+
+        impl<S: BaseFloat> Transform<$PointN<S>> for $IsometryN<S> {
+            #[inline]
+            fn one() -> $IsometryN<S> {
+                $IsometryN::new($Rotation::one(), $VectorN::zero())
+            }
+
+            #[inline]
+            fn look_at(eye: $PointN<S>, center: $PointN<S>, up: $VectorN<S>) -> $IsometryN<S> {
+                let rotation = $Rotation::look_at(center - eye, up);
+                $IsometryN::new(rotation, rotation.rotate_vector($PointN::origin() - eye))
+            }
+
+            #[inline]
+            fn transform_vector(&self, vec: $VectorN<S>) -> $VectorN<S> {
+                self.rotation.rotate_vector(vec)
+            }
+
+            #[inline]
+            fn inverse_transform_vector(&self, vec: $VectorN<S>) -> Option<$VectorN<S>> {
+                Some(self.rotation.invert().rotate_vector(vec))
+            }
+
+            #[inline]
+            fn transform_point(&self, point: $PointN<S>) -> $PointN<S> {
+                self.rotation.rotate_point(point) + self.translation
+            }
+
+            #[inline]
+            fn concat(&self, other: &$IsometryN<S>) -> $IsometryN<S> {
+                $IsometryN::new(self.rotation * other.rotation,
+                                self.rotation.rotate_vector(other.translation) + self.translation)
+            }
+
+            #[inline]
+            fn inverse_transform(&self) -> Option<$IsometryN<S>> {
+                Some(self.inverse())
+            }
+        }
+
+        impl<S: BaseFloat> $TransformN<S> for $IsometryN<S> {}
+
+        impl<S: BaseFloat> $SimilarityN<S> {
+            /// Construct a similarity from a scale, a rotation and a
+            /// translation.
+            #[inline]
+            pub fn new(scale: S, rotation: $Rotation<S>, translation: $VectorN<S>) -> $SimilarityN<S> {
+                $SimilarityN { scale, rotation, translation }
+            }
+
+            /// A similarity that only scales.
+            #[inline]
+            pub fn from_scale(scale: S) -> $SimilarityN<S> {
+                $SimilarityN::new(scale, $Rotation::one(), $VectorN::zero())
+            }
+
+            /// The inverse of this similarity. The scale must be non-zero.
+            #[inline]
+            pub fn inverse(&self) -> $SimilarityN<S> {
+                let scale = self.scale.recip();
+                let rotation = self.rotation.invert();
+                $SimilarityN::new(scale, rotation, -rotation.rotate_vector(self.translation) * scale)
+            }
+
+            /// The rigid part of this similarity, if its scale is one.
+            #[inline]
+            pub fn to_isometry(&self) -> Option<$IsometryN<S>> {
+                if relative_eq!(self.scale, S::one()) {
+                    Some($IsometryN::new(self.rotation, self.translation))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<S: BaseFloat> Transform<$PointN<S>> for $SimilarityN<S> {
+            #[inline]
+            fn one() -> $SimilarityN<S> {
+                $SimilarityN::from_scale(S::one())
+            }
+
+            #[inline]
+            fn look_at(eye: $PointN<S>, center: $PointN<S>, up: $VectorN<S>) -> $SimilarityN<S> {
+                $IsometryN::look_at(eye, center, up).into()
+            }
+
+            #[inline]
+            fn transform_vector(&self, vec: $VectorN<S>) -> $VectorN<S> {
+                self.rotation.rotate_vector(vec * self.scale)
+            }
+
+            #[inline]
+            fn inverse_transform_vector(&self, vec: $VectorN<S>) -> Option<$VectorN<S>> {
+                if ulps_eq!(self.scale, &S::zero()) {
+                    None
+                } else {
+                    Some(self.rotation.invert().rotate_vector(vec) / self.scale)
+                }
+            }
+
+            #[inline]
+            fn transform_point(&self, point: $PointN<S>) -> $PointN<S> {
+                self.rotation.rotate_point(point * self.scale) + self.translation
+            }
+
+            #[inline]
+            fn concat(&self, other: &$SimilarityN<S>) -> $SimilarityN<S> {
+                $SimilarityN::new(self.scale * other.scale,
+                                  self.rotation * other.rotation,
+                                  self.transform_vector(other.translation) + self.translation)
+            }
+
+            #[inline]
+            fn inverse_transform(&self) -> Option<$SimilarityN<S>> {
+                if ulps_eq!(self.scale, &S::zero()) {
+                    None
+                } else {
+                    Some(self.inverse())
+                }
+            }
+        }
+
+        impl<S: BaseFloat> $TransformN<S> for $SimilarityN<S> {}
+
+        impl<S: BaseFloat> From<$IsometryN<S>> for $SimilarityN<S> {
+            #[inline]
+            fn from(iso: $IsometryN<S>) -> $SimilarityN<S> {
+                $SimilarityN::new(S::one(), iso.rotation, iso.translation)
+            }
+        }
+
+        impl<S: BaseFloat> From<$IsometryN<S>> for Decomposed<$VectorN<S>, $Rotation<S>> {
+            #[inline]
+            fn from(iso: $IsometryN<S>) -> Decomposed<$VectorN<S>, $Rotation<S>> {
+                $SimilarityN::from(iso).into()
+            }
+        }
+
+        impl<S: BaseFloat> From<$SimilarityN<S>> for Decomposed<$VectorN<S>, $Rotation<S>> {
+            #[inline]
+            fn from(sim: $SimilarityN<S>) -> Decomposed<$VectorN<S>, $Rotation<S>> {
+                Decomposed { scale: sim.scale, rot: sim.rotation, disp: sim.translation }
+            }
+        }
+
+        impl<S: BaseFloat> From<Decomposed<$VectorN<S>, $Rotation<S>>> for $SimilarityN<S> {
+            #[inline]
+            fn from(dec: Decomposed<$VectorN<S>, $Rotation<S>>) -> $SimilarityN<S> {
+                $SimilarityN::new(dec.scale, dec.rot, dec.disp)
+            }
+        }
+
+        impl<S: BaseFloat> From<$IsometryN<S>> for $HomogeneousN<S> {
+            #[inline]
+            fn from(iso: $IsometryN<S>) -> $HomogeneousN<S> {
+                Decomposed::from(iso).into()
+            }
+        }
+
+        impl<S: BaseFloat> From<$SimilarityN<S>> for $HomogeneousN<S> {
+            #[inline]
+            fn from(sim: $SimilarityN<S>) -> $HomogeneousN<S> {
+                Decomposed::from(sim).into()
+            }
+        }
+
+        impl<S: BaseFloat> AbsDiffEq for $IsometryN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $Rotation::abs_diff_eq(&self.rotation, &other.rotation, epsilon) &&
+                $VectorN::abs_diff_eq(&self.translation, &other.translation, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $IsometryN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $Rotation::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative) &&
+                $VectorN::relative_eq(&self.translation, &other.translation, epsilon, max_relative)
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $IsometryN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $Rotation::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps) &&
+                $VectorN::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+            }
+        }
+
+        impl<S: BaseFloat> AbsDiffEq for $SimilarityN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                S::abs_diff_eq(&self.scale, &other.scale, epsilon) &&
+                $Rotation::abs_diff_eq(&self.rotation, &other.rotation, epsilon) &&
+                $VectorN::abs_diff_eq(&self.translation, &other.translation, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $SimilarityN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                S::relative_eq(&self.scale, &other.scale, epsilon, max_relative) &&
+                $Rotation::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative) &&
+                $VectorN::relative_eq(&self.translation, &other.translation, epsilon, max_relative)
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $SimilarityN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                S::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps) &&
+                $Rotation::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps) &&
+                $VectorN::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+            }
+        }
+    }
+}
+
+impl_isometry!(Isometry2, Similarity2, Point2, Vector2, Matrix3, Basis2, Transform2);
+impl_isometry!(Isometry3, Similarity3, Point3, Vector3, Matrix4, Quaternion, Transform3);
+
+impl<S: BaseFloat> Isometry2<S> {
+    /// Extract the rotation and translation from an affine matrix, returning
+    /// `None` if it also scales, shears or reflects.
+    pub fn from_matrix3(m: Matrix3<S>) -> Option<Isometry2<S>> {
+        Similarity2::from_matrix3(m)?.to_isometry()
+    }
+}
+
+impl<S: BaseFloat> Similarity2<S> {
+    /// Extract the uniform scale, rotation and translation from an affine
+    /// matrix, returning `None` if it has a non-uniform scale or shear.
+    ///
+    /// Unlike `Similarity3::from_matrix4`, this also returns `None` for
+    /// reflections, because a negative scale in two dimensions is a rotation.
+    pub fn from_matrix3(m: Matrix3<S>) -> Option<Similarity2<S>> {
+        let affine = ulps_eq!(m.x.z, &S::zero()) && ulps_eq!(m.y.z, &S::zero()) &&
+                     ulps_eq!(m.z.z, &S::one());
+        let (x, y) = (m.x.truncate(), m.y.truncate());
+        let scale = x.magnitude();
+        if !affine || scale == S::zero() || !relative_eq!(y.magnitude(), scale) {
+            return None;
+        }
+
+        // Reject shears with the same tolerance as `Matrix4::decompose`
+        let sheared = x.dot(y).abs() > S::epsilon().sqrt() * scale * scale;
+        if sheared || x.perp_dot(y) < S::zero() {
+            return None;
+        }
+
+        let rotation = Basis2::from_angle(Rad::atan2(x.y, x.x));
+        Some(Similarity2::new(scale, rotation, m.z.truncate()))
+    }
+}
+
+impl<S: BaseFloat> Isometry3<S> {
+    /// Extract the rotation and translation from an affine matrix, returning
+    /// `None` if it also scales, shears or reflects.
+    pub fn from_matrix4(m: Matrix4<S>) -> Option<Isometry3<S>> {
+        Similarity3::from_matrix4(m)?.to_isometry()
+    }
+}
+
+impl<S: BaseFloat> Similarity3<S> {
+    /// Extract the uniform scale, rotation and translation from an affine
+    /// matrix, returning `None` if it has a non-uniform scale or shear.
+    ///
+    /// A reflection through the origin is represented by a negative scale.
+    pub fn from_matrix4(m: Matrix4<S>) -> Option<Similarity3<S>> {
+        let dec = m.decompose().ok()?.to_decomposed()?;
+        Some(dec.into())
+    }
+}
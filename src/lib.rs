@@ -40,6 +40,13 @@
 //! - `ElementWise`: For element-wise addition, subtraction, multiplication,
 //!   division, and remainder operations.
 //!
+//! # Matrix naming
+//!
+//! Matrices are stored in column major order, and non-square matrices are
+//! named by their number of rows and then columns, so `Matrix2x3` has 2 rows
+//! and 3 columns. GLSL names its matrices the other way around: `Matrix2x3`
+//! has the layout of a GLSL `mat3x2`.
+//!
 //! # The prelude
 //!
 //! Importing each trait individually can become a chore, so we provide a
@@ -49,8 +56,22 @@
 //! ```rust
 //! use cgmath::prelude::*;
 //! ```
+//!
+//! # Features
+//!
+//! The `std` and `rand` features are enabled by default. Without `std` the
+//! crate is `no_std`, and the `libm` feature must be enabled instead to
+//! provide the floating point functions. Implementations of the `rand`
+//! traits, along with the samplers in this crate, require the `rand` feature.
+//! The `rand` and `mint` features pull in crates that depend on `std`.
 
-#![cfg_attr(feature = "simd", feature(specialization))]
+#![no_std]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("cgmath requires either the `std` or the `libm` feature");
+
+#[cfg(feature = "std")]
+extern crate std;
 
 #[macro_use]
 extern crate approx;
@@ -59,15 +80,13 @@ extern crate approx;
 pub extern crate mint;
 
 pub extern crate num_traits;
+#[cfg(feature = "rand")]
 extern crate rand;
 
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
 
-#[cfg(feature = "simd")]
-extern crate simd;
-
 // Re-exports
 
 pub use approx::*;
@@ -75,17 +94,32 @@ pub use num::*;
 pub use structure::*;
 
 pub use matrix::{Matrix2, Matrix3, Matrix4};
+pub use matrix::{Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3};
+pub use decomposition::{Cholesky, DecomposeError, Lu, Qr, Svd, SymmetricEigen, Trs};
 pub use quaternion::Quaternion;
+pub use dual_quaternion::DualQuaternion;
 pub use vector::{Vector1, Vector2, Vector3, Vector4, dot, vec1, vec2, vec3, vec4};
+pub use wide::{Quaternionx4, Quaternionx8, Vector3x4, Vector3x8};
 
 pub use angle::{Deg, Rad};
-pub use euler::Euler;
+pub use euler::{Euler, EulerAngles, EulerFrame, EulerSequence};
 pub use point::{Point1, Point2, Point3};
 pub use rotation::*;
 pub use transform::*;
+pub use isometry::{Isometry2, Isometry3, Similarity2, Similarity3};
+pub use affine::{Affine2, Affine3};
 
 pub use projection::*;
 
+#[cfg(feature = "rand")]
+pub use random::{CosineHemisphere, Triangle, UniformRotation, UnitBall, UnitCircle, UnitDisk, UnitSphere};
+
+pub use aabb::{Aabb2, Aabb3};
+pub use frustum::{Frustum, Relation};
+pub use plane::Plane;
+pub use ray::Ray3;
+pub use sphere::Sphere;
+
 // Modules
 
 pub mod conv;
@@ -93,17 +127,34 @@ pub mod prelude;
 
 mod macros;
 
+#[cfg(feature = "simd")]
+mod arch;
+
 mod num;
 mod structure;
 
 mod matrix;
+mod decomposition;
 mod quaternion;
+mod dual_quaternion;
 mod vector;
+pub mod wide;
 
 mod angle;
 mod euler;
 mod point;
 mod rotation;
 mod transform;
+mod isometry;
+mod affine;
 
 mod projection;
+
+#[cfg(feature = "rand")]
+mod random;
+
+mod aabb;
+mod frustum;
+mod plane;
+mod ray;
+mod sphere;
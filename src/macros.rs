@@ -128,6 +128,29 @@ macro_rules! impl_assignment_operator {
     };
 }
 
+/// Returns early with the result of an `arch` kernel, when the `simd` feature
+/// is enabled and the kernel supports the operand types. With a place before
+/// the kernel, the result is stored there instead of being returned.
+macro_rules! simd_fast_path {
+    ($kernel:expr) => {
+        #[cfg(feature = "simd")]
+        {
+            if let Some(result) = $kernel {
+                return result;
+            }
+        }
+    };
+    ($place:expr; $kernel:expr) => {
+        #[cfg(feature = "simd")]
+        {
+            if let Some(result) = $kernel {
+                $place = result;
+                return;
+            }
+        }
+    };
+}
+
 macro_rules! fold_array {
     (&$method:ident, { $x:expr })                            => { *$x };
     (&$method:ident, { $x:expr, $y:expr })                   => { $x.$method(&$y) };
@@ -255,208 +278,6 @@ macro_rules! impl_index_operators {
     }
 }
 
-#[cfg(feature = "simd")]
-macro_rules! impl_operator_default {
-    // When it is an unary operator
-    (<$S:ident: $Constraint:ident> $Op:ident for $Lhs:ty {
-        fn $op:ident($x:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl<$S: $Constraint> $Op for $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self) -> $Output {
-                let $x = self; $body
-            }
-        }
-
-        impl<'a, $S: $Constraint> $Op for &'a $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self) -> $Output {
-                let $x = self; $body
-            }
-        }
-    };
-    // When the right operand is a scalar
-    (<$S:ident: $Constraint:ident> $Op:ident<$Rhs:ident> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl<$S: $Constraint> $Op<$Rhs> for $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-
-        impl<'a, $S: $Constraint> $Op<$Rhs> for &'a $Lhs {
-          type Output = $Output;
-            #[inline]
-            default fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-    };
-    // When the right operand is a compound type
-    (<$S:ident: $Constraint:ident> $Op:ident<$Rhs:ty> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl<$S: $Constraint> $Op<$Rhs> for $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-
-        impl<'a, $S: $Constraint> $Op<&'a $Rhs> for $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-
-        impl<'a, $S: $Constraint> $Op<$Rhs> for &'a $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-
-        impl<'a, 'b, $S: $Constraint> $Op<&'a $Rhs> for &'b $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-    };
-    // When the left operand is a scalar
-    ($Op:ident<$Rhs:ident<$S:ident>> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op<$Rhs<$S>> for $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: $Rhs<$S>) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-
-        impl<'a> $Op<&'a $Rhs<$S>> for $Lhs {
-           type Output = $Output;
-            #[inline]
-            default fn $op(self, other: &'a $Rhs<$S>) -> $Output {
-                let ($lhs, $rhs) = (self, other); $body
-            }
-        }
-    };
-}
-
-#[cfg(feature = "simd")]
-macro_rules! impl_assignment_operator_default {
-    (<$S:ident: $Constraint:ident> $Op:ident<$Rhs:ty> for $Lhs:ty {
-        fn $op:ident(&mut $lhs:ident, $rhs:ident) $body:block
-    }) => {
-        impl<$S: $Constraint + $Op<$S>> $Op<$Rhs> for $Lhs {
-            #[inline]
-            default fn $op(&mut $lhs, $rhs: $Rhs) $body
-        }
-    };
-}
-
-/// Generates a binary operator implementation for the permutations of by-ref and by-val, for simd
-#[cfg(feature = "simd")]
-macro_rules! impl_operator_simd {
-    // When it is an unary operator
-    ([$Simd:ident]; $Op:ident for $Lhs:ty {
-        fn $op:ident($x:ident) -> $Output:ty { $body:expr }
-    }) => {
-
-        impl $Op for $Lhs {
-            #[inline]
-            fn $op(self) -> $Output {
-                let $x: $Simd = self.into(); $body
-            }
-        }
-    };
-    // When the right operand is a scalar
-    (@rs [$Simd:ident]; $Op:ident<$Rhs:ty> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op<$Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = (self.into(), $Simd::splat(other)); $body
-            }
-        }
-
-
-        impl<'a> $Op<$Rhs> for &'a $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ((*self).into(), $Simd::splat(other)); $body
-            }
-        }
-    };
-
-    // When the right operand is a compound type
-    ([$Simd:ident]; $Op:ident<$Rhs:ty> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-
-        impl $Op<$Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = (self.into(), other.into()); $body
-            }
-        }
-
-
-        impl<'a> $Op<&'a $Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = (self.into(), (*other).into()); $body
-            }
-        }
-
-        impl<'a> $Op<$Rhs> for &'a $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ((*self).into(), other.into()); $body
-            }
-        }
-
-        impl<'a, 'b> $Op<&'a $Rhs> for &'b $Lhs {
-            #[inline]
-            fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ((*self).into(), (*other).into()); $body
-            }
-        }
-    };
-
-    // When the left operand is a scalar
-    (@ls [$Simd:ident]; $Op:ident<$Rhs:ty> for $Lhs:ident {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op<$Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ($Simd::splat(self), other.into()); $body
-            }
-        }
-
-        impl<'a> $Op<&'a $Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ($Simd::splat(self), (*other).into()); $body
-            }
-        }
-    };
-}
-
 /// Generate `mint` types conversion implementations
 #[cfg(feature = "mint")]
 macro_rules! impl_mint_conversions {
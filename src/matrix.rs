@@ -13,25 +13,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::{cast, NumCast};
-use std::fmt;
-use std::iter;
-use std::mem;
-use std::ops::*;
-use std::ptr;
+use core::fmt;
+use core::iter;
+use core::mem;
+use core::ops::*;
+use core::ptr;
 
 use structure::*;
 
 use angle::Rad;
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use euler::Euler;
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+use rotation::Rotation3;
 use transform::{Transform, Transform2, Transform3};
 use vector::{Vector2, Vector3, Vector4};
 
+#[cfg(feature = "simd")]
+use arch;
+
 #[cfg(feature = "mint")]
 use mint;
 
@@ -80,6 +85,108 @@ pub struct Matrix4<S> {
     pub w: Vector4<S>,
 }
 
+/// A 2 x 3, column major matrix, with 2 rows and 3 columns
+///
+/// There is no `mint` conversion, as `mint` has no column major matrix of
+/// this shape.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix2x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+}
+
+/// A 3 x 2, column major matrix, with 3 rows and 2 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix3x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+}
+
+/// A 2 x 4, column major matrix, with 2 rows and 4 columns
+///
+/// There is no `mint` conversion, as `mint` has no column major matrix of
+/// this shape.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix2x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector2<S>,
+}
+
+/// A 4 x 2, column major matrix, with 4 rows and 2 columns
+///
+/// There is no `mint` conversion, as `mint` has no column major matrix of
+/// this shape.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+}
+
+/// A 3 x 4, column major matrix, with 3 rows and 4 columns
+///
+/// There is no `mint` conversion, as `mint` has no column major matrix of
+/// this shape.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix3x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+    /// The third column of the matrix.
+    pub z: Vector3<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector3<S>,
+}
+
+/// A 4 x 3, column major matrix, with 4 rows and 3 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+    /// The third column of the matrix.
+    pub z: Vector4<S>,
+}
+
 
 impl<S: BaseFloat> Matrix2<S> {
     /// Create a new matrix, providing values for each index.
@@ -185,6 +292,15 @@ impl<S: BaseFloat> Matrix3<S> {
                      _1subc * axis.y * axis.z - s * axis.x,
                      _1subc * axis.z * axis.z + c)
     }
+
+    /// Extract the axis and angle of a rotation matrix, as described by
+    /// `Rotation3::to_axis_angle`.
+    ///
+    /// The matrix **must be a rotation**, or the result is meaningless.
+    #[inline]
+    pub fn to_axis_angle(self) -> (Vector3<S>, Rad<S>) {
+        Quaternion::from(self).to_axis_angle()
+    }
 }
 
 impl<S: BaseFloat> Matrix4<S> {
@@ -306,6 +422,147 @@ impl<S: BaseFloat> Matrix4<S> {
                      S::zero(),
                      S::one())
     }
+
+    /// Transform each normal in `src` by the inverse transpose of the upper
+    /// left 3x3 part of this matrix, writing the normalized results to `dst`.
+    ///
+    /// Every `transform_normals` normalizes its results, as scaling geometry
+    /// would otherwise change the length of its normals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn transform_normals(&self, src: &[Vector3<S>], dst: &mut [Vector3<S>]) {
+        dst.copy_from_slice(src);
+        self.transform_normals_in_place(dst);
+    }
+
+    /// Transform each normal in a slice in-place, like `transform_normals`.
+    pub fn transform_normals_in_place(&self, normals: &mut [Vector3<S>]) {
+        // The cofactor matrix is the inverse transpose scaled by the
+        // determinant, so only its sign needs correcting before the results
+        // are normalized
+        let (a, b, c) = (self.x.truncate(), self.y.truncate(), self.z.truncate());
+        let (bc, ca, ab) = (b.cross(c), c.cross(a), a.cross(b));
+        let sign = if a.dot(bc) < S::zero() { -S::one() } else { S::one() };
+        let cofactor = Matrix4::from_cols((bc * sign).extend(S::zero()),
+                                          (ca * sign).extend(S::zero()),
+                                          (ab * sign).extend(S::zero()),
+                                          Vector4::unit_w());
+        cofactor.transform_vectors_in_place(normals);
+        for normal in normals {
+            *normal = normal.normalize();
+        }
+    }
+}
+
+impl<S: BaseFloat> Matrix2x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S,
+               c1r0: S, c1r1: S,
+               c2r0: S, c2r1: S) -> Matrix2x3<S> {
+        Matrix2x3::from_cols(Vector2::new(c0r0, c0r1),
+                             Vector2::new(c1r0, c1r1),
+                             Vector2::new(c2r0, c2r1))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>) -> Matrix2x3<S> {
+        Matrix2x3 { x: c0, y: c1, z: c2 }
+    }
+}
+
+impl<S: BaseFloat> Matrix3x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S,
+               c1r0: S, c1r1: S, c1r2: S) -> Matrix3x2<S> {
+        Matrix3x2::from_cols(Vector3::new(c0r0, c0r1, c0r2),
+                             Vector3::new(c1r0, c1r1, c1r2))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector3<S>, c1: Vector3<S>) -> Matrix3x2<S> {
+        Matrix3x2 { x: c0, y: c1 }
+    }
+}
+
+impl<S: BaseFloat> Matrix2x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S,
+               c1r0: S, c1r1: S,
+               c2r0: S, c2r1: S,
+               c3r0: S, c3r1: S) -> Matrix2x4<S> {
+        Matrix2x4::from_cols(Vector2::new(c0r0, c0r1),
+                             Vector2::new(c1r0, c1r1),
+                             Vector2::new(c2r0, c2r1),
+                             Vector2::new(c3r0, c3r1))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>, c3: Vector2<S>) -> Matrix2x4<S> {
+        Matrix2x4 { x: c0, y: c1, z: c2, w: c3 }
+    }
+}
+
+impl<S: BaseFloat> Matrix4x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+               c1r0: S, c1r1: S, c1r2: S, c1r3: S) -> Matrix4x2<S> {
+        Matrix4x2::from_cols(Vector4::new(c0r0, c0r1, c0r2, c0r3),
+                             Vector4::new(c1r0, c1r1, c1r2, c1r3))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector4<S>, c1: Vector4<S>) -> Matrix4x2<S> {
+        Matrix4x2 { x: c0, y: c1 }
+    }
+}
+
+impl<S: BaseFloat> Matrix3x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S,
+               c1r0: S, c1r1: S, c1r2: S,
+               c2r0: S, c2r1: S, c2r2: S,
+               c3r0: S, c3r1: S, c3r2: S) -> Matrix3x4<S> {
+        Matrix3x4::from_cols(Vector3::new(c0r0, c0r1, c0r2),
+                             Vector3::new(c1r0, c1r1, c1r2),
+                             Vector3::new(c2r0, c2r1, c2r2),
+                             Vector3::new(c3r0, c3r1, c3r2))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector3<S>, c1: Vector3<S>, c2: Vector3<S>, c3: Vector3<S>) -> Matrix3x4<S> {
+        Matrix3x4 { x: c0, y: c1, z: c2, w: c3 }
+    }
+}
+
+impl<S: BaseFloat> Matrix4x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+               c1r0: S, c1r1: S, c1r2: S, c1r3: S,
+               c2r0: S, c2r1: S, c2r2: S, c2r3: S) -> Matrix4x3<S> {
+        Matrix4x3::from_cols(Vector4::new(c0r0, c0r1, c0r2, c0r3),
+                             Vector4::new(c1r0, c1r1, c1r2, c1r3),
+                             Vector4::new(c2r0, c2r1, c2r2, c2r3))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>) -> Matrix4x3<S> {
+        Matrix4x3 { x: c0, y: c1, z: c2 }
+    }
 }
 
 impl<S: BaseFloat> Zero for Matrix2<S> {
@@ -670,12 +927,6 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
                      self[3][3])
     }
 
-    // The new implementation results in negative optimization when used
-    // without SIMD. so we opt them in with configuration.
-    // A better option would be using specialization. But currently somewhat
-    // specialization is too buggy, and it won't apply here. I'm getting
-    // weird error msgs. Help wanted.
-    #[cfg(not(feature = "simd"))]
     fn invert(&self) -> Option<Matrix4<S>> {
         let det = self.determinant();
         if det == S::zero() {
@@ -701,30 +952,6 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
                               cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3)))
         }
     }
-    #[cfg(feature = "simd")]
-    fn invert(&self) -> Option<Matrix4<S>> {
-        let tmp0 = unsafe {
-            det_sub_proc_unsafe(self, 1, 2, 3)
-        };
-        let det = tmp0.dot(Vector4::new(self[0][0], self[1][0], self[2][0], self[3][0]));
-
-        if det == S::zero() {
-            None
-        } else {
-            let inv_det = S::one() / det;
-            let tmp0 = tmp0 * inv_det;
-            let tmp1 = unsafe {
-                det_sub_proc_unsafe(self, 0, 3, 2) * inv_det
-            };
-            let tmp2 = unsafe {
-                det_sub_proc_unsafe(self, 0, 1, 3) * inv_det
-            };
-            let tmp3 = unsafe {
-                det_sub_proc_unsafe(self, 0, 2, 1) * inv_det
-            };
-            Some(Matrix4::from_cols(tmp0, tmp1, tmp2, tmp3))
-        }
-    }
 
     fn is_diagonal(&self) -> bool {
         ulps_eq!(self[0][1], &S::zero()) &&
@@ -763,7 +990,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
     }
 }
 
-impl<S: BaseFloat> ApproxEq for Matrix2<S> {
+impl<S: BaseFloat> AbsDiffEq for Matrix2<S> {
     type Epsilon = S::Epsilon;
 
     #[inline]
@@ -772,13 +999,16 @@ impl<S: BaseFloat> ApproxEq for Matrix2<S> {
     }
 
     #[inline]
-    fn default_max_relative() -> S::Epsilon {
-        S::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector2::abs_diff_eq(&self[0], &other[0], epsilon) &&
+        Vector2::abs_diff_eq(&self[1], &other[1], epsilon)
     }
+}
 
+impl<S: BaseFloat> RelativeEq for Matrix2<S> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        S::default_max_ulps()
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
     }
 
     #[inline]
@@ -786,6 +1016,13 @@ impl<S: BaseFloat> ApproxEq for Matrix2<S> {
         Vector2::relative_eq(&self[0], &other[0], epsilon, max_relative) &&
         Vector2::relative_eq(&self[1], &other[1], epsilon, max_relative)
     }
+}
+
+impl<S: BaseFloat> UlpsEq for Matrix2<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -794,7 +1031,7 @@ impl<S: BaseFloat> ApproxEq for Matrix2<S> {
     }
 }
 
-impl<S: BaseFloat> ApproxEq for Matrix3<S> {
+impl<S: BaseFloat> AbsDiffEq for Matrix3<S> {
     type Epsilon = S::Epsilon;
 
     #[inline]
@@ -803,13 +1040,17 @@ impl<S: BaseFloat> ApproxEq for Matrix3<S> {
     }
 
     #[inline]
-    fn default_max_relative() -> S::Epsilon {
-        S::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self[0], &other[0], epsilon) &&
+        Vector3::abs_diff_eq(&self[1], &other[1], epsilon) &&
+        Vector3::abs_diff_eq(&self[2], &other[2], epsilon)
     }
+}
 
+impl<S: BaseFloat> RelativeEq for Matrix3<S> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        S::default_max_ulps()
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
     }
 
     #[inline]
@@ -818,6 +1059,13 @@ impl<S: BaseFloat> ApproxEq for Matrix3<S> {
         Vector3::relative_eq(&self[1], &other[1], epsilon, max_relative) &&
         Vector3::relative_eq(&self[2], &other[2], epsilon, max_relative)
     }
+}
+
+impl<S: BaseFloat> UlpsEq for Matrix3<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -827,7 +1075,7 @@ impl<S: BaseFloat> ApproxEq for Matrix3<S> {
     }
 }
 
-impl<S: BaseFloat> ApproxEq for Matrix4<S> {
+impl<S: BaseFloat> AbsDiffEq for Matrix4<S> {
     type Epsilon = S::Epsilon;
 
     #[inline]
@@ -836,13 +1084,18 @@ impl<S: BaseFloat> ApproxEq for Matrix4<S> {
     }
 
     #[inline]
-    fn default_max_relative() -> S::Epsilon {
-        S::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector4::abs_diff_eq(&self[0], &other[0], epsilon) &&
+        Vector4::abs_diff_eq(&self[1], &other[1], epsilon) &&
+        Vector4::abs_diff_eq(&self[2], &other[2], epsilon) &&
+        Vector4::abs_diff_eq(&self[3], &other[3], epsilon)
     }
+}
 
+impl<S: BaseFloat> RelativeEq for Matrix4<S> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        S::default_max_ulps()
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
     }
 
     #[inline]
@@ -852,6 +1105,13 @@ impl<S: BaseFloat> ApproxEq for Matrix4<S> {
         Vector4::relative_eq(&self[2], &other[2], epsilon, max_relative) &&
         Vector4::relative_eq(&self[3], &other[3], epsilon, max_relative)
     }
+}
+
+impl<S: BaseFloat> UlpsEq for Matrix4<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -862,6 +1122,119 @@ impl<S: BaseFloat> ApproxEq for Matrix4<S> {
     }
 }
 
+macro_rules! impl_nonsquare_matrix {
+    ($MatrixN:ident, $Column:ident { $($field:ident),+ }, $Row:ident { $($row_index:expr),+ },
+     $Transpose:ident, $cols:expr, $rows:expr) => {
+        impl<S: BaseFloat> Zero for $MatrixN<S> {
+            #[inline]
+            fn zero() -> $MatrixN<S> {
+                $MatrixN { $($field: $Column::zero()),+ }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                ulps_eq!(self, &Self::zero())
+            }
+        }
+
+        impl<S: BaseFloat> VectorSpace for $MatrixN<S> {
+            type Scalar = S;
+        }
+
+        impl<S: BaseFloat> Matrix for $MatrixN<S> {
+            type Column = $Column<S>;
+            type Row = $Row<S>;
+            type Transpose = $Transpose<S>;
+
+            #[inline]
+            fn row(&self, r: usize) -> $Row<S> {
+                $Row::new($(self.$field[r]),+)
+            }
+
+            #[inline]
+            fn swap_rows(&mut self, a: usize, b: usize) {
+                $(self.$field.swap_elements(a, b);)+
+            }
+
+            #[inline]
+            fn swap_columns(&mut self, a: usize, b: usize) {
+                unsafe { ptr::swap(&mut self[a], &mut self[b]) };
+            }
+
+            #[inline]
+            fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+                let (ac, ar) = a;
+                let (bc, br) = b;
+                unsafe { ptr::swap(&mut self[ac][ar], &mut self[bc][br]) };
+            }
+
+            fn transpose(&self) -> $Transpose<S> {
+                $Transpose::from_cols($(self.row($row_index)),+)
+            }
+        }
+
+        impl<S: BaseFloat> AbsDiffEq for $MatrixN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                cast(1.0e-6f64).unwrap()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $($Column::abs_diff_eq(&self.$field, &other.$field, epsilon))&&+
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $MatrixN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $($Column::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $MatrixN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $($Column::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
+            }
+        }
+
+        impl<S: fmt::Debug> fmt::Debug for $MatrixN<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} ", stringify!($MatrixN))?;
+                <[[S; $rows]; $cols] as fmt::Debug>::fmt(self.as_ref(), f)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl<S: BaseFloat + Rand> Rand for $MatrixN<S> {
+            #[inline]
+            fn rand<R: Rng>(rng: &mut R) -> $MatrixN<S> {
+                $MatrixN { $($field: rng.gen()),+ }
+            }
+        }
+    }
+}
+
+impl_nonsquare_matrix!(Matrix2x3, Vector2 { x, y, z }, Vector3 { 0, 1 }, Matrix3x2, 3, 2);
+impl_nonsquare_matrix!(Matrix3x2, Vector3 { x, y }, Vector2 { 0, 1, 2 }, Matrix2x3, 2, 3);
+impl_nonsquare_matrix!(Matrix2x4, Vector2 { x, y, z, w }, Vector4 { 0, 1 }, Matrix4x2, 4, 2);
+impl_nonsquare_matrix!(Matrix4x2, Vector4 { x, y }, Vector2 { 0, 1, 2, 3 }, Matrix2x4, 2, 4);
+impl_nonsquare_matrix!(Matrix3x4, Vector3 { x, y, z, w }, Vector4 { 0, 1, 2 }, Matrix4x3, 4, 3);
+impl_nonsquare_matrix!(Matrix4x3, Vector4 { x, y, z }, Vector3 { 0, 1, 2, 3 }, Matrix3x4, 3, 4);
+
 impl<S: BaseFloat> Transform<Point2<S>> for Matrix3<S> {
   fn one() -> Matrix3<S> {
     One::one()
@@ -933,6 +1306,30 @@ impl<S: BaseFloat> Transform<Point3<S>> for Matrix4<S> {
     Point3::from_homogeneous(self * point.to_homogeneous())
   }
 
+  fn transform_points_in_place(&self, points: &mut [Point3<S>]) {
+    #[cfg(feature = "simd")]
+    {
+      if arch::transform_points(self, points) {
+        return;
+      }
+    }
+    for point in points {
+      *point = self.transform_point(*point);
+    }
+  }
+
+  fn transform_vectors_in_place(&self, vectors: &mut [Vector3<S>]) {
+    #[cfg(feature = "simd")]
+    {
+      if arch::transform_vectors(self, vectors) {
+        return;
+      }
+    }
+    for vec in vectors {
+      *vec = self.transform_vector(*vec);
+    }
+  }
+
   fn concat(&self, other: &Matrix4<S>) -> Matrix4<S> {
     self * other
   }
@@ -1000,20 +1397,6 @@ macro_rules! impl_matrix {
             }
         }
 
-        impl<S: BaseFloat> iter::Product for $MatrixN<S> {
-            #[inline]
-            fn product<I: Iterator<Item=$MatrixN<S>>>(iter: I) -> $MatrixN<S> {
-                iter.fold($MatrixN::identity(), Mul::mul)
-            }
-        }
-
-        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $MatrixN<S>> for $MatrixN<S> {
-            #[inline]
-            fn product<I: Iterator<Item=&'a $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
-                iter.fold($MatrixN::identity(), Mul::mul)
-            }
-        }
-
         impl_scalar_ops!($MatrixN<usize> { $($field),+ });
         impl_scalar_ops!($MatrixN<u8> { $($field),+ });
         impl_scalar_ops!($MatrixN<u16> { $($field),+ });
@@ -1058,26 +1441,64 @@ macro_rules! impl_scalar_ops {
     };
 }
 
+macro_rules! impl_square_matrix_product {
+    ($MatrixN:ident) => {
+        impl<S: BaseFloat> iter::Product for $MatrixN<S> {
+            #[inline]
+            fn product<I: Iterator<Item=$MatrixN<S>>>(iter: I) -> $MatrixN<S> {
+                iter.fold($MatrixN::identity(), Mul::mul)
+            }
+        }
+
+        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $MatrixN<S>> for $MatrixN<S> {
+            #[inline]
+            fn product<I: Iterator<Item=&'a $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
+                iter.fold($MatrixN::identity(), Mul::mul)
+            }
+        }
+    }
+}
+
 impl_matrix!(Matrix2, Vector2 { x: 0, y: 1 });
 impl_matrix!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
 impl_matrix!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix2x3, Vector2 { x: 0, y: 1, z: 2 });
+impl_matrix!(Matrix3x2, Vector3 { x: 0, y: 1 });
+impl_matrix!(Matrix2x4, Vector2 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix4x2, Vector4 { x: 0, y: 1 });
+impl_matrix!(Matrix3x4, Vector3 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix4x3, Vector4 { x: 0, y: 1, z: 2 });
+
+impl_square_matrix_product!(Matrix2);
+impl_square_matrix_product!(Matrix3);
+impl_square_matrix_product!(Matrix4);
 
 macro_rules! impl_mv_operator {
-    ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
-        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $MatrixN<S> {
+    ($MatrixN:ident * $Input:ident = $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
+        impl_operator!(<S: BaseFloat> Mul<$Input<S> > for $MatrixN<S> {
             fn mul(matrix, vector) -> $VectorN<S> {$VectorN::new($(matrix.row($row_index).dot(vector.clone())),+)}
         });
     }
 }
 
-impl_mv_operator!(Matrix2, Vector2 { x: 0, y: 1 });
-impl_mv_operator!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
-#[cfg(not(feature = "simd"))]
-impl_mv_operator!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
-#[cfg(feature = "simd")]
+impl_mv_operator!(Matrix2 * Vector2 = Vector2 { x: 0, y: 1 });
+impl_mv_operator!(Matrix3 * Vector3 = Vector3 { x: 0, y: 1, z: 2 });
+impl_mv_operator!(Matrix2x3 * Vector3 = Vector2 { x: 0, y: 1 });
+impl_mv_operator!(Matrix3x2 * Vector2 = Vector3 { x: 0, y: 1, z: 2 });
+impl_mv_operator!(Matrix2x4 * Vector4 = Vector2 { x: 0, y: 1 });
+impl_mv_operator!(Matrix4x2 * Vector2 = Vector4 { x: 0, y: 1, z: 2, w: 3 });
+impl_mv_operator!(Matrix3x4 * Vector4 = Vector3 { x: 0, y: 1, z: 2 });
+impl_mv_operator!(Matrix4x3 * Vector3 = Vector4 { x: 0, y: 1, z: 2, w: 3 });
+
 impl_operator!(<S: BaseFloat> Mul<Vector4<S> > for Matrix4<S> {
     fn mul(matrix, vector) -> Vector4<S> {
-        matrix[0] * vector[0] + matrix[1] * vector[1] + matrix[2] * vector[2] + matrix[3] * vector[3]
+        {
+            simd_fast_path!(arch::mul_matrix4_vector4(matrix.clone(), vector.clone()));
+            Vector4::new(matrix.row(0).dot(vector.clone()),
+                         matrix.row(1).dot(vector.clone()),
+                         matrix.row(2).dot(vector.clone()),
+                         matrix.row(3).dot(vector.clone()))
+        }
     }
 });
 
@@ -1105,6 +1526,7 @@ impl_operator!(<S: BaseFloat> Mul<Matrix3<S> > for Matrix3<S> {
 impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
     fn mul(lhs, rhs) -> Matrix4<S> {
         {
+            simd_fast_path!(arch::mul_matrix4(lhs.clone(), rhs.clone()));
             let a = lhs[0];
             let b = lhs[1];
             let c = lhs[2];
@@ -1119,14 +1541,47 @@ impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
     }
 });
 
+macro_rules! impl_mm_operator {
+    ($Lhs:ident * $Rhs:ident = $Output:ident { $($field:ident),+ }) => {
+        impl_operator!(<S: BaseFloat> Mul<$Rhs<S> > for $Lhs<S> {
+            fn mul(lhs, rhs) -> $Output<S> { $Output::from_cols($(lhs * rhs.$field),+) }
+        });
+    }
+}
+
+impl_mm_operator!(Matrix2 * Matrix2x3 = Matrix2x3 { x, y, z });
+impl_mm_operator!(Matrix2 * Matrix2x4 = Matrix2x4 { x, y, z, w });
+impl_mm_operator!(Matrix2x3 * Matrix3x2 = Matrix2 { x, y });
+impl_mm_operator!(Matrix2x3 * Matrix3 = Matrix2x3 { x, y, z });
+impl_mm_operator!(Matrix2x3 * Matrix3x4 = Matrix2x4 { x, y, z, w });
+impl_mm_operator!(Matrix2x4 * Matrix4x2 = Matrix2 { x, y });
+impl_mm_operator!(Matrix2x4 * Matrix4x3 = Matrix2x3 { x, y, z });
+impl_mm_operator!(Matrix2x4 * Matrix4 = Matrix2x4 { x, y, z, w });
+impl_mm_operator!(Matrix3x2 * Matrix2 = Matrix3x2 { x, y });
+impl_mm_operator!(Matrix3x2 * Matrix2x3 = Matrix3 { x, y, z });
+impl_mm_operator!(Matrix3x2 * Matrix2x4 = Matrix3x4 { x, y, z, w });
+impl_mm_operator!(Matrix3 * Matrix3x2 = Matrix3x2 { x, y });
+impl_mm_operator!(Matrix3 * Matrix3x4 = Matrix3x4 { x, y, z, w });
+impl_mm_operator!(Matrix3x4 * Matrix4x2 = Matrix3x2 { x, y });
+impl_mm_operator!(Matrix3x4 * Matrix4x3 = Matrix3 { x, y, z });
+impl_mm_operator!(Matrix3x4 * Matrix4 = Matrix3x4 { x, y, z, w });
+impl_mm_operator!(Matrix4x2 * Matrix2 = Matrix4x2 { x, y });
+impl_mm_operator!(Matrix4x2 * Matrix2x3 = Matrix4x3 { x, y, z });
+impl_mm_operator!(Matrix4x2 * Matrix2x4 = Matrix4 { x, y, z, w });
+impl_mm_operator!(Matrix4x3 * Matrix3x2 = Matrix4x2 { x, y });
+impl_mm_operator!(Matrix4x3 * Matrix3 = Matrix4x3 { x, y, z });
+impl_mm_operator!(Matrix4x3 * Matrix3x4 = Matrix4 { x, y, z, w });
+impl_mm_operator!(Matrix4 * Matrix4x2 = Matrix4x2 { x, y });
+impl_mm_operator!(Matrix4 * Matrix4x3 = Matrix4x3 { x, y, z });
+
 macro_rules! index_operators {
-    ($MatrixN:ident<$S:ident>, $n:expr, $Output:ty, $I:ty) => {
+    ($MatrixN:ident<$S:ident>, $cols:expr, $rows:expr, $Output:ty, $I:ty) => {
         impl<$S> Index<$I> for $MatrixN<$S> {
             type Output = $Output;
 
             #[inline]
             fn index<'a>(&'a self, i: $I) -> &'a $Output {
-                let v: &[[$S; $n]; $n] = self.as_ref();
+                let v: &[[$S; $rows]; $cols] = self.as_ref();
                 From::from(&v[i])
             }
         }
@@ -1134,28 +1589,34 @@ macro_rules! index_operators {
         impl<$S> IndexMut<$I> for $MatrixN<$S> {
             #[inline]
             fn index_mut<'a>(&'a mut self, i: $I) -> &'a mut $Output {
-                let v: &mut [[$S; $n]; $n] = self.as_mut();
+                let v: &mut [[$S; $rows]; $cols] = self.as_mut();
                 From::from(&mut v[i])
             }
         }
     }
 }
 
-index_operators!(Matrix2<S>, 2, Vector2<S>, usize);
-index_operators!(Matrix3<S>, 3, Vector3<S>, usize);
-index_operators!(Matrix4<S>, 4, Vector4<S>, usize);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], Range<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], Range<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], Range<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeTo<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeTo<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeTo<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFrom<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFrom<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFrom<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFull);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFull);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFull);
+index_operators!(Matrix2<S>, 2, 2, Vector2<S>, usize);
+index_operators!(Matrix3<S>, 3, 3, Vector3<S>, usize);
+index_operators!(Matrix4<S>, 4, 4, Vector4<S>, usize);
+index_operators!(Matrix2x3<S>, 3, 2, Vector2<S>, usize);
+index_operators!(Matrix3x2<S>, 2, 3, Vector3<S>, usize);
+index_operators!(Matrix2x4<S>, 4, 2, Vector2<S>, usize);
+index_operators!(Matrix4x2<S>, 2, 4, Vector4<S>, usize);
+index_operators!(Matrix3x4<S>, 4, 3, Vector3<S>, usize);
+index_operators!(Matrix4x3<S>, 3, 4, Vector4<S>, usize);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], Range<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], Range<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], Range<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeTo<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeTo<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeTo<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeFrom<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeFrom<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeFrom<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeFull);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeFull);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeFull);
 
 impl<A> From<Euler<A>> for Matrix3<A::Unitless> where
     A: Angle + Into<Rad<<A as Angle>::Unitless>>,
@@ -1189,99 +1650,105 @@ impl<A> From<Euler<A>> for Matrix4<A::Unitless> where
 }
 
 macro_rules! fixed_array_conversions {
-    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $n:expr) => {
-        impl<$S> Into<[[$S; $n]; $n]> for $MatrixN<$S> {
+    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $cols:expr, $rows:expr) => {
+        impl<$S> Into<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn into(self) -> [[$S; $n]; $n] {
+            fn into(self) -> [[$S; $rows]; $cols] {
                 match self { $MatrixN { $($field),+ } => [$($field.into()),+] }
             }
         }
 
-        impl<$S> AsRef<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsRef<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[[$S; $n]; $n] {
+            fn as_ref(&self) -> &[[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsMut<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [[$S; $n]; $n] {
+            fn as_mut(&mut self) -> &mut [[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S: Copy> From<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S: Copy> From<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn from(m: [[$S; $n]; $n]) -> $MatrixN<$S> {
+            fn from(m: [[$S; $rows]; $cols]) -> $MatrixN<$S> {
                 // We need to use a copy here because we can't pattern match on arrays yet
                 $MatrixN { $($field: From::from(m[$index])),+ }
             }
         }
 
-        impl<'a, $S> From<&'a [[$S; $n]; $n]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [[$S; $rows]; $cols]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [[$S; $n]; $n]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [[$S; $rows]; $cols]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [[$S; $n]; $n]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [[$S; $rows]; $cols]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [[$S; $n]; $n]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [[$S; $rows]; $cols]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        // impl<$S> Into<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> Into<[$S; ($rows * $cols)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn into(self) -> [[$S; $n]; $n] {
+        //     fn into(self) -> [[$S; $rows]; $cols] {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<$S> AsRef<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsRef<[$S; ($rows * $cols)]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[$S; ($n * $n)] {
+            fn as_ref(&self) -> &[$S; ($rows * $cols)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsMut<[$S; ($rows * $cols)]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [$S; ($n * $n)] {
+            fn as_mut(&mut self) -> &mut [$S; ($rows * $cols)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        // impl<$S> From<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> From<[$S; ($rows * $cols)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
+        //     fn from(m: [$S; ($rows * $cols)]) -> $MatrixN<$S> {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<'a, $S> From<&'a [$S; ($n * $n)]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [$S; ($rows * $cols)]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [$S; ($n * $n)]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [$S; ($rows * $cols)]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [$S; ($n * $n)]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [$S; ($rows * $cols)]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [$S; ($n * $n)]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [$S; ($rows * $cols)]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
     }
 }
 
-fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2);
-fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3);
-fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4);
+fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2, 2);
+fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3, 3);
+fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4, 4);
+fixed_array_conversions!(Matrix2x3<S> { x:0, y:1, z:2 }, 3, 2);
+fixed_array_conversions!(Matrix3x2<S> { x:0, y:1 }, 2, 3);
+fixed_array_conversions!(Matrix2x4<S> { x:0, y:1, z:2, w:3 }, 4, 2);
+fixed_array_conversions!(Matrix4x2<S> { x:0, y:1 }, 2, 4);
+fixed_array_conversions!(Matrix3x4<S> { x:0, y:1, z:2, w:3 }, 4, 3);
+fixed_array_conversions!(Matrix4x3<S> { x:0, y:1, z:2 }, 3, 4);
 
 #[cfg(feature = "mint")]
 macro_rules! mint_conversions {
@@ -1309,6 +1776,10 @@ mint_conversions!(Matrix2 { x, y }, ColumnMatrix2);
 mint_conversions!(Matrix3 { x, y, z }, ColumnMatrix3);
 #[cfg(feature = "mint")]
 mint_conversions!(Matrix4 { x, y, z, w }, ColumnMatrix4);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix3x2 { x, y }, ColumnMatrix3x2);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix4x3 { x, y, z }, ColumnMatrix4x3);
 
 impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
@@ -1406,6 +1877,7 @@ impl<S: fmt::Debug> fmt::Debug for Matrix4<S> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Matrix2<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix2<S> {
@@ -1413,6 +1885,7 @@ impl<S: BaseFloat + Rand> Rand for Matrix2<S> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Matrix3<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix3<S> {
@@ -1420,6 +1893,7 @@ impl<S: BaseFloat + Rand> Rand for Matrix3<S> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Matrix4<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix4<S> {
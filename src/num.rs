@@ -13,10 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use num_traits::{Float, Num, NumCast};
 
@@ -26,6 +26,6 @@ pub trait BaseNum: Copy + Clone + fmt::Debug + Num + NumCast + PartialOrd + AddA
 impl<T> BaseNum for T where T: Copy + Clone + fmt::Debug + Num + NumCast + PartialOrd + AddAssign + SubAssign + MulAssign + DivAssign + RemAssign {}
 
 /// Base floating point types
-pub trait BaseFloat: BaseNum + Float + ApproxEq<Epsilon = Self> {}
+pub trait BaseFloat: BaseNum + Float + AbsDiffEq<Epsilon = Self> + RelativeEq<Epsilon = Self> + UlpsEq<Epsilon = Self> {}
 
-impl<T> BaseFloat for T where T: BaseNum + Float + ApproxEq<Epsilon = Self> {}
+impl<T> BaseFloat for T where T: BaseNum + Float + AbsDiffEq<Epsilon = Self> + RelativeEq<Epsilon = Self> + UlpsEq<Epsilon = Self> {}
@@ -0,0 +1,135 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::BaseFloat;
+use point::Point3;
+use vector::Vector3;
+
+/// A plane in three-dimensional space, in Hessian normal form.
+///
+/// The plane is the set of points `p` for which `normal.dot(p) == distance`.
+/// When the normal has unit length, `distance` is the signed distance of the
+/// plane from the origin, measured along the normal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plane<S> {
+    /// The normal of the plane.
+    pub normal: Vector3<S>,
+    /// The distance of the plane from the origin, along the normal.
+    pub distance: S,
+}
+
+impl<S: BaseFloat> Plane<S> {
+    /// Construct a plane from a normal and a distance from the origin.
+    #[inline]
+    pub fn new(normal: Vector3<S>, distance: S) -> Plane<S> {
+        Plane { normal, distance }
+    }
+
+    /// Construct a plane from the coefficients of the plane equation
+    /// `a*x + b*y + c*z + d = 0`.
+    #[inline]
+    pub fn from_abcd(a: S, b: S, c: S, d: S) -> Plane<S> {
+        Plane::new(Vector3::new(a, b, c), -d)
+    }
+
+    /// Construct a plane passing through `point` with the given `normal`.
+    ///
+    /// The normal **should be normalized** for `signed_distance` to return
+    /// true distances.
+    #[inline]
+    pub fn from_point_normal(point: Point3<S>, normal: Vector3<S>) -> Plane<S> {
+        Plane::new(normal, point.dot(normal))
+    }
+
+    /// Construct the plane passing through three points. The normal points
+    /// towards the side from which `a`, `b` and `c` appear counter-clockwise.
+    ///
+    /// Returns `None` if the points are collinear.
+    pub fn from_points(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<Plane<S>> {
+        let (ab, ac) = (b - a, c - a);
+        let normal = ab.cross(ac);
+        // Compare against the size of the edges, so that small triangles are
+        // not mistaken for degenerate ones
+        let epsilon = S::default_epsilon();
+        if normal.magnitude2() <= ab.magnitude2() * ac.magnitude2() * epsilon * epsilon {
+            None
+        } else {
+            Some(Plane::from_point_normal(a, normal.normalize()))
+        }
+    }
+
+    /// Return a plane describing the same set of points, but with a unit
+    /// length normal.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Plane<S> {
+        let inv_mag = self.normal.magnitude().recip();
+        Plane::new(self.normal * inv_mag, self.distance * inv_mag)
+    }
+
+    /// The signed distance from the plane to `point`. This is positive when
+    /// the point lies on the side of the plane that the normal points towards.
+    ///
+    /// The result is only a true distance if the normal is normalized.
+    #[inline]
+    pub fn signed_distance(&self, point: Point3<S>) -> S {
+        point.dot(self.normal) - self.distance
+    }
+}
+
+impl<S: BaseFloat> AbsDiffEq for Plane<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.normal, &other.normal, epsilon) &&
+        S::abs_diff_eq(&self.distance, &other.distance, epsilon)
+    }
+}
+
+impl<S: BaseFloat> RelativeEq for Plane<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.normal, &other.normal, epsilon, max_relative) &&
+        S::relative_eq(&self.distance, &other.distance, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> UlpsEq for Plane<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.normal, &other.normal, epsilon, max_ulps) &&
+        S::ulps_eq(&self.distance, &other.distance, epsilon, max_ulps)
+    }
+}
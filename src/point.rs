@@ -18,13 +18,13 @@
 //! not have a fixed position.
 
 use num_traits::{NumCast, Bounded};
-use std::fmt;
-use std::mem;
-use std::ops::*;
+use core::fmt;
+use core::mem;
+use core::ops::*;
 
 use structure::*;
 
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num::{BaseNum, BaseFloat};
 use vector::{Vector1, Vector2, Vector3, Vector4};
 
@@ -178,7 +178,7 @@ macro_rules! impl_point {
             }
         }
 
-        impl<S: BaseFloat> ApproxEq for $PointN<S> {
+        impl<S: BaseFloat> AbsDiffEq for $PointN<S> {
             type Epsilon = S::Epsilon;
 
             #[inline]
@@ -187,19 +187,28 @@ macro_rules! impl_point {
             }
 
             #[inline]
-            fn default_max_relative() -> S::Epsilon {
-                S::default_max_relative()
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $(S::abs_diff_eq(&self.$field, &other.$field, epsilon))&&+
             }
+        }
 
+        impl<S: BaseFloat> RelativeEq for $PointN<S> {
             #[inline]
-            fn default_max_ulps() -> u32 {
-                S::default_max_ulps()
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
             }
 
             #[inline]
             fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
                 $(S::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
             }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $PointN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
 
             #[inline]
             fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -16,11 +16,14 @@
 use num_traits::{Zero};
 use num_traits::cast;
 
-use structure::Angle;
+use structure::*;
 
 use angle::Rad;
 use matrix::Matrix4;
 use num::BaseFloat;
+use point::{Point2, Point3};
+use ray::Ray3;
+use vector::{Vector3, Vector4};
 
 /// Create a perspective projection matrix.
 ///
@@ -35,6 +38,66 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, f
     }.into()
 }
 
+/// Create a perspective projection matrix with an infinite far plane.
+///
+/// This maps depth to the same `[-1, 1]` range as `perspective`, with points
+/// infinitely far away mapping to `1`.
+pub fn perspective_infinite<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S) -> Matrix4<S> {
+    PerspectiveFovInfinite {
+        fovy:   fovy.into(),
+        aspect,
+        near,
+    }.into()
+}
+
+/// Create a perspective projection matrix with reversed depth, mapping the
+/// near plane to a depth of `1` and the far plane to a depth of `0`.
+pub fn perspective_reverse_z<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
+    PerspectiveFovReverseZ {
+        fovy:   fovy.into(),
+        aspect,
+        near,
+        far,
+    }.into()
+}
+
+/// Create a perspective projection matrix with reversed depth and an infinite
+/// far plane, mapping the near plane to a depth of `1` and points infinitely
+/// far away to a depth of `0`.
+pub fn perspective_reverse_z_infinite<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S) -> Matrix4<S> {
+    PerspectiveFovReverseZInfinite {
+        fovy:   fovy.into(),
+        aspect,
+        near,
+    }.into()
+}
+
+/// Map a point from world space to window coordinates, using the OpenGL
+/// convention.
+///
+/// This is the equivalent of the [gluProject]
+/// (https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluProject.xml)
+/// function. See `Convention::project` for details.
+pub fn project<S: BaseFloat>(point: Point3<S>, view: Matrix4<S>, proj: Matrix4<S>, viewport: Viewport<S>) -> Point3<S> {
+    Convention::opengl().project(point, view, proj, viewport)
+}
+
+/// Map a point from window coordinates back to world space, using the OpenGL
+/// convention.
+///
+/// This is the equivalent of the [gluUnProject]
+/// (https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluUnProject.xml)
+/// function. See `Convention::unproject` for details.
+pub fn unproject<S: BaseFloat>(window: Point3<S>, view: Matrix4<S>, proj: Matrix4<S>, viewport: Viewport<S>) -> Option<Point3<S>> {
+    Convention::opengl().unproject(window, view, proj, viewport)
+}
+
+/// Build a world space ray passing through the cursor position, using the
+/// OpenGL convention. See `Convention::pick_ray` for details.
+pub fn pick_ray<S: BaseFloat>(cursor: Point2<S>, view: Matrix4<S>, proj: Matrix4<S>, viewport: Viewport<S>) -> Option<Ray3<S>> {
+    Convention::opengl().pick_ray(cursor, view, proj, viewport)
+}
+
 /// Create a perspective matrix from a view frustum.
 ///
 /// This is the equivalent of the now deprecated [glFrustum]
@@ -133,6 +196,93 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
     }
 }
 
+/// A perspective projection based on a vertical field-of-view angle, with the
+/// far plane placed at infinity.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerspectiveFovInfinite<S> {
+    pub fovy:   Rad<S>,
+    pub aspect: S,
+    pub near:   S,
+}
+
+impl<S: BaseFloat> From<PerspectiveFovInfinite<S>> for Matrix4<S> {
+    fn from(persp: PerspectiveFovInfinite<S>) -> Matrix4<S> {
+        let (sx, sy) = fov_scale(persp.fovy, persp.aspect, persp.near);
+        let two: S = cast(2).unwrap();
+
+        Matrix4::new(sx,        S::zero(), S::zero(),         S::zero(),
+                     S::zero(), sy,        S::zero(),         S::zero(),
+                     S::zero(), S::zero(), -S::one(),         -S::one(),
+                     S::zero(), S::zero(), -two * persp.near, S::zero())
+    }
+}
+
+/// A perspective projection based on a vertical field-of-view angle, with
+/// reversed depth: the near plane maps to a depth of `1`, and the far plane to
+/// a depth of `0`.
+///
+/// Because floating point numbers are much more precise close to zero, this
+/// spreads depth precision far more evenly over the view than the standard
+/// mapping.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerspectiveFovReverseZ<S> {
+    pub fovy:   Rad<S>,
+    pub aspect: S,
+    pub near:   S,
+    pub far:    S,
+}
+
+impl<S: BaseFloat> From<PerspectiveFovReverseZ<S>> for Matrix4<S> {
+    fn from(persp: PerspectiveFovReverseZ<S>) -> Matrix4<S> {
+        let (sx, sy) = fov_scale(persp.fovy, persp.aspect, persp.near);
+        assert!(persp.far > persp.near, "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}", persp.far, persp.near);
+
+        let depth = persp.far - persp.near;
+
+        Matrix4::new(sx,        S::zero(), S::zero(),                      S::zero(),
+                     S::zero(), sy,        S::zero(),                      S::zero(),
+                     S::zero(), S::zero(), persp.near / depth,             -S::one(),
+                     S::zero(), S::zero(), persp.far * persp.near / depth, S::zero())
+    }
+}
+
+/// A perspective projection based on a vertical field-of-view angle, with
+/// reversed depth and the far plane placed at infinity: the near plane maps to
+/// a depth of `1`, and points infinitely far away to a depth of `0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerspectiveFovReverseZInfinite<S> {
+    pub fovy:   Rad<S>,
+    pub aspect: S,
+    pub near:   S,
+}
+
+impl<S: BaseFloat> From<PerspectiveFovReverseZInfinite<S>> for Matrix4<S> {
+    fn from(persp: PerspectiveFovReverseZInfinite<S>) -> Matrix4<S> {
+        let (sx, sy) = fov_scale(persp.fovy, persp.aspect, persp.near);
+
+        Matrix4::new(sx,        S::zero(), S::zero(),  S::zero(),
+                     S::zero(), sy,        S::zero(),  S::zero(),
+                     S::zero(), S::zero(), S::zero(),  -S::one(),
+                     S::zero(), S::zero(), persp.near, S::zero())
+    }
+}
+
+/// Check the parameters shared by the field-of-view based projections, and
+/// return the scale factors for the `x` and `y` axes.
+fn fov_scale<S: BaseFloat>(fovy: Rad<S>, aspect: S, near: S) -> (S, S) {
+    assert!(fovy   > Rad::zero(), "The vertical field of view cannot be below zero, found: {:?}", fovy);
+    assert!(fovy   < Rad::turn_div_2(), "The vertical field of view cannot be greater than a half turn, found: {:?}", fovy);
+    assert!(aspect > S::zero(), "The aspect ratio cannot be below zero, found: {:?}", aspect);
+    assert!(near   > S::zero(), "The near plane distance cannot be below zero, found: {:?}", near);
+
+    let two: S = cast(2).unwrap();
+    let f = Rad::cot(fovy / two);
+    (f / aspect, f)
+}
+
 /// A perspective projection with arbitrary left/right/bottom/top distances
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -222,3 +372,243 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
                      c3r0, c3r1, c3r2, c3r3)
     }
 }
+
+/// A rectangular region of the window that is rendered to.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Viewport<S> {
+    pub x:      S,
+    pub y:      S,
+    pub width:  S,
+    pub height: S,
+}
+
+impl<S> Viewport<S> {
+    /// Construct a viewport from the position of its corner and its size.
+    #[inline]
+    pub fn new(x: S, y: S, width: S, height: S) -> Viewport<S> {
+        Viewport { x, y, width, height }
+    }
+}
+
+/// The handedness of view space.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Handedness {
+    /// The camera looks down the negative `z` axis.
+    Right,
+    /// The camera looks down the positive `z` axis.
+    Left,
+}
+
+/// The range of depth values in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// The near plane maps to `-1`, and the far plane to `1`.
+    NegativeOneToOne,
+    /// The near plane maps to `0`, and the far plane to `1`.
+    ZeroToOne,
+    /// The near plane maps to `1`, and the far plane to `0`, as produced by
+    /// `perspective_reverse_z` and `perspective_reverse_z_infinite`.
+    OneToZero,
+}
+
+/// The conventions a graphics API uses for view and clip space.
+///
+/// The free functions in this module and `Matrix4::look_at` follow the OpenGL
+/// convention, which is also the `Default`. The methods on this type build
+/// the same matrices for other conventions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Convention {
+    /// The handedness of view space.
+    pub handedness: Handedness,
+    /// The range of depth values in normalized device coordinates.
+    pub depth: DepthRange,
+    /// Whether the `y` axis points down in normalized device coordinates.
+    pub flip_y: bool,
+}
+
+impl Convention {
+    /// Right-handed view space, with depth in `[-1, 1]` and `y` pointing up.
+    #[inline]
+    pub fn opengl() -> Convention {
+        Convention { handedness: Handedness::Right, depth: DepthRange::NegativeOneToOne, flip_y: false }
+    }
+
+    /// Right-handed view space, with depth in `[0, 1]` and `y` pointing down.
+    #[inline]
+    pub fn vulkan() -> Convention {
+        Convention { handedness: Handedness::Right, depth: DepthRange::ZeroToOne, flip_y: true }
+    }
+
+    /// Left-handed view space, with depth in `[0, 1]` and `y` pointing up.
+    #[inline]
+    pub fn direct3d() -> Convention {
+        Convention { handedness: Handedness::Left, depth: DepthRange::ZeroToOne, flip_y: false }
+    }
+
+    /// Right-handed view space, with depth in `[0, 1]` and `y` pointing up.
+    #[inline]
+    pub fn metal() -> Convention {
+        Convention { handedness: Handedness::Right, depth: DepthRange::ZeroToOne, flip_y: false }
+    }
+
+    /// This convention with reversed depth, mapping the near plane to `1` and
+    /// the far plane to `0`, as used by `perspective_reverse_z`.
+    ///
+    /// Reversed depth is only useful with a floating point depth buffer, so
+    /// this always uses a depth range of `[0, 1]`.
+    #[inline]
+    pub fn reverse_z(self) -> Convention {
+        Convention { depth: DepthRange::OneToZero, ..self }
+    }
+
+    /// Convert a projection that maps right-handed view space into OpenGL
+    /// clip space, such as `PerspectiveFov`, `Perspective` or `Ortho`, so that
+    /// it follows this convention instead.
+    pub fn projection<S: BaseFloat, P: Into<Matrix4<S>>>(&self, proj: P) -> Matrix4<S> {
+        let mut m = proj.into();
+
+        if self.handedness == Handedness::Left {
+            m.z = -m.z;
+        }
+
+        let half: S = cast(0.5).unwrap();
+        match self.depth {
+            DepthRange::NegativeOneToOne => {},
+            DepthRange::ZeroToOne => for c in 0..4 {
+                m[c].z = (m[c].z + m[c].w) * half;
+            },
+            DepthRange::OneToZero => for c in 0..4 {
+                m[c].z = (m[c].w - m[c].z) * half;
+            },
+        }
+
+        if self.flip_y {
+            for c in 0..4 {
+                m[c].y = -m[c].y;
+            }
+        }
+
+        m
+    }
+
+    /// Create a perspective projection matrix, as with `perspective`.
+    pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(&self, fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
+        self.projection(perspective(fovy, aspect, near, far))
+    }
+
+    /// Create a perspective matrix from a view frustum, as with `frustum`.
+    pub fn frustum<S: BaseFloat>(&self, left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+        self.projection(frustum(left, right, bottom, top, near, far))
+    }
+
+    /// Create an orthographic projection matrix, as with `ortho`.
+    pub fn ortho<S: BaseFloat>(&self, left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+        self.projection(ortho(left, right, bottom, top, near, far))
+    }
+
+    /// Create a view matrix looking along `dir`, as with
+    /// `Matrix4::look_at_dir`.
+    pub fn look_at_dir<S: BaseFloat>(&self, eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
+        let view = Matrix4::look_at_dir(eye, dir, up);
+        match self.handedness {
+            Handedness::Right => view,
+            // Turn the camera around to look down the positive `z` axis
+            Handedness::Left => Matrix4::from_nonuniform_scale(-S::one(), S::one(), -S::one()) * view,
+        }
+    }
+
+    /// Create a view matrix looking at `center`, as with `Matrix4::look_at`.
+    pub fn look_at<S: BaseFloat>(&self, eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        self.look_at_dir(eye, center - eye, up)
+    }
+
+    /// Map a point from world space to window coordinates.
+    ///
+    /// The `x` and `y` window coordinates lie within `viewport`, with the `y`
+    /// axis pointing the same way as in normalized device coordinates: up
+    /// for OpenGL, and down for Vulkan. The `z` coordinate is the depth, from
+    /// `0` at the near plane to `1` at the far plane, or the other way around
+    /// for `DepthRange::OneToZero`.
+    pub fn project<S: BaseFloat>(&self, point: Point3<S>, view: Matrix4<S>, proj: Matrix4<S>, viewport: Viewport<S>) -> Point3<S> {
+        let ndc = Point3::from_homogeneous(proj * (view * point.to_homogeneous()));
+
+        let two: S = cast(2).unwrap();
+        let depth = match self.depth {
+            DepthRange::NegativeOneToOne => (ndc.z + S::one()) / two,
+            DepthRange::ZeroToOne | DepthRange::OneToZero => ndc.z,
+        };
+
+        Point3::new(viewport.x + viewport.width * (ndc.x + S::one()) / two,
+                    viewport.y + viewport.height * (ndc.y + S::one()) / two,
+                    depth)
+    }
+
+    /// Map a point from window coordinates back to world space. This is the
+    /// inverse of `project`.
+    ///
+    /// Returns `None` if `proj * view` is not invertible.
+    pub fn unproject<S: BaseFloat>(&self, window: Point3<S>, view: Matrix4<S>, proj: Matrix4<S>, viewport: Viewport<S>) -> Option<Point3<S>> {
+        let inv = (proj * view).invert()?;
+
+        let two: S = cast(2).unwrap();
+        let depth = match self.depth {
+            DepthRange::NegativeOneToOne => window.z * two - S::one(),
+            DepthRange::ZeroToOne | DepthRange::OneToZero => window.z,
+        };
+        let ndc = Vector4::new((window.x - viewport.x) / viewport.width * two - S::one(),
+                               (window.y - viewport.y) / viewport.height * two - S::one(),
+                               depth,
+                               S::one());
+
+        Some(Point3::from_homogeneous(inv * ndc))
+    }
+
+    /// Build a world space ray passing through the cursor position, for
+    /// picking objects under the mouse.
+    ///
+    /// `cursor` is measured in pixels from the top left corner of `viewport`,
+    /// with the `y` axis pointing down, as reported by most windowing systems.
+    /// The ray starts on the near plane and has a normalized direction. The
+    /// depth range of the convention decides which window depth is the near
+    /// plane, so reverse-Z projections need a `Convention::reverse_z`
+    /// convention.
+    ///
+    /// Returns `None` if `proj * view` is not invertible, or if the cursor
+    /// does not map to a finite ray.
+    pub fn pick_ray<S: BaseFloat>(&self, cursor: Point2<S>, view: Matrix4<S>, proj: Matrix4<S>, viewport: Viewport<S>) -> Option<Ray3<S>> {
+        let y = if self.flip_y {
+            viewport.y + cursor.y
+        } else {
+            viewport.y + viewport.height - cursor.y
+        };
+        let x = viewport.x + cursor.x;
+
+        // The far plane may be at infinity, so aim through a point halfway
+        // through the depth range instead
+        let near_depth = match self.depth {
+            DepthRange::NegativeOneToOne | DepthRange::ZeroToOne => S::zero(),
+            DepthRange::OneToZero => S::one(),
+        };
+        let half: S = cast(0.5).unwrap();
+        let origin = self.unproject(Point3::new(x, y, near_depth), view, proj, viewport)?;
+        let through = self.unproject(Point3::new(x, y, half), view, proj, viewport)?;
+
+        let direction = through - origin;
+        let finite = |v: Vector3<S>| v.x.is_finite() && v.y.is_finite() && v.z.is_finite();
+        if !finite(origin.to_vec()) || !finite(direction) || direction.is_zero() {
+            return None;
+        }
+        Some(Ray3::new(origin, direction.normalize()))
+    }
+}
+
+impl Default for Convention {
+    #[inline]
+    fn default() -> Convention {
+        Convention::opengl()
+    }
+}
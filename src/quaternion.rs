@@ -13,26 +13,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::iter;
-use std::mem;
-use std::ops::*;
+use core::iter;
+use core::mem;
+use core::ops::*;
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::{NumCast, cast};
 
 use structure::*;
 
 use angle::Rad;
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use euler::Euler;
 use matrix::{Matrix3, Matrix4};
 use num::BaseFloat;
 use point::Point3;
 use rotation::{Rotation, Rotation3, Basis3};
-use vector::Vector3;
+use vector::{Vector3, Vector4};
 
 #[cfg(feature = "simd")]
-use simd::f32x4 as Simdf32x4;
+use arch;
 
 #[cfg(feature = "mint")]
 use mint;
@@ -51,30 +52,6 @@ pub struct Quaternion<S> {
     pub v: Vector3<S>,
 }
 
-#[cfg(feature = "simd")]
-impl From<Simdf32x4> for Quaternion<f32> {
-    #[inline]
-    fn from(f: Simdf32x4) -> Self {
-        unsafe {
-            let mut ret: Self = mem::uninitialized();
-            {
-                let ret_mut: &mut [f32; 4] = ret.as_mut();
-                f.store(ret_mut.as_mut(), 0 as usize);
-            }
-            ret
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl Into<Simdf32x4> for Quaternion<f32> {
-    #[inline]
-    fn into(self) -> Simdf32x4 {
-        let self_ref: &[f32; 4] = self.as_ref();
-        Simdf32x4::load(self_ref.as_ref(), 0 as usize)
-    }
-}
-
 impl<S: BaseFloat> Quaternion<S> {
     /// Construct a new quaternion from one scalar component and three
     /// imaginary components.
@@ -117,6 +94,93 @@ impl<S: BaseFloat> Quaternion<S> {
         }
     }
 
+    /// The time derivative of the rotation when it is rotating with
+    /// `angular_velocity`, given in radians per unit time about the fixed
+    /// world axes.
+    ///
+    /// This is `½ ω q`, where `ω` is the pure quaternion `(0, angular_velocity)`.
+    #[inline]
+    pub fn derivative(self, angular_velocity: Vector3<S>) -> Quaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        Quaternion::from_sv(S::zero(), angular_velocity * half) * self
+    }
+
+    /// Advance the rotation by rotating with a constant `angular_velocity`,
+    /// about the fixed world axes, for a time `dt`.
+    ///
+    /// This uses the exact exponential map, `exp(½ ω dt) q`, so the result
+    /// stays normalized and is exact for any timestep. It is the inverse of
+    /// `angular_velocity`.
+    #[inline]
+    pub fn integrate(self, angular_velocity: Vector3<S>, dt: S) -> Quaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        Quaternion::from_sv(S::zero(), angular_velocity * (half * dt)).exp() * self
+    }
+
+    /// The constant angular velocity, about the fixed world axes, that turns
+    /// `from` into `to` over a time `dt`, taking the shortest path.
+    ///
+    /// Both quaternions should be normalized, and `dt` must be non-zero.
+    pub fn angular_velocity(from: Quaternion<S>, to: Quaternion<S>, dt: S) -> Vector3<S> {
+        let two: S = cast(2.0f64).unwrap();
+        let delta = to * from.conjugate();
+        let delta = if delta.s < S::zero() { -delta } else { delta };
+        delta.ln().v * (two / dt)
+    }
+
+    /// The mean of a set of rotations, or `None` if `rotations` is empty.
+    ///
+    /// See `weighted_mean` for details.
+    #[inline]
+    pub fn mean(rotations: &[Quaternion<S>]) -> Option<Quaternion<S>> {
+        Quaternion::mean_of(rotations.iter().map(|&q| (q, S::one())))
+    }
+
+    /// The weighted mean of a set of rotations, which should be normalized.
+    ///
+    /// This is the unit quaternion that minimizes the weighted sum of squared
+    /// distances between the rotation matrices, found as the eigenvector of
+    /// `Σ wᵢ qᵢ qᵢᵀ` with the largest eigenvalue. Unlike repeatedly calling
+    /// `nlerp` or `slerp`, the result does not depend on the order of the
+    /// rotations or the signs of the quaternions. The returned quaternion has
+    /// a non-negative scalar part.
+    ///
+    /// This is the method described in Markley et al., "Averaging
+    /// Quaternions". Returns `None` if there are no rotations or the weights
+    /// sum to zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rotations` and `weights` have different lengths.
+    pub fn weighted_mean(rotations: &[Quaternion<S>], weights: &[S]) -> Option<Quaternion<S>> {
+        assert_eq!(rotations.len(), weights.len());
+        Quaternion::mean_of(rotations.iter().cloned().zip(weights.iter().cloned()))
+    }
+
+    /// The weighted mean of `(rotation, weight)` pairs, as described in
+    /// `weighted_mean`.
+    pub(crate) fn mean_of<I>(weighted: I) -> Option<Quaternion<S>>
+        where I: IntoIterator<Item = (Quaternion<S>, S)>
+    {
+        let mut m = Matrix4::zero();
+        let (mut total, mut total_abs) = (S::zero(), S::zero());
+        for (q, w) in weighted {
+            let v = Vector4::new(q.s, q.v.x, q.v.y, q.v.z);
+            m += Matrix4::from_cols(v * v.x, v * v.y, v * v.z, v * v.w) * w;
+            total += w;
+            total_abs += w.abs();
+        }
+        // Only the relative size of the weights matters, so compare the sum
+        // against their magnitudes rather than an absolute epsilon
+        if total.abs() <= total_abs * S::epsilon() {
+            return None;
+        }
+
+        let e = m.symmetric_eigen().eigenvectors.x;
+        let e = if e.x < S::zero() { -e } else { e };
+        Some(Quaternion::new(e.x, e.y, e.z, e.w).normalize())
+    }
+
     /// The conjugate of the quaternion.
     #[inline]
     pub fn conjugate(self) -> Quaternion<S> {
@@ -170,6 +234,135 @@ impl<S: BaseFloat> Quaternion<S> {
             (self * scale1 + other * scale2) * Rad::sin(theta).recip()
         }
     }
+
+    /// The exponential of the quaternion.
+    ///
+    /// For a pure quaternion `(0, θ/2 n)` this is the rotation by `θ` about
+    /// the unit axis `n`.
+    pub fn exp(self) -> Quaternion<S> {
+        let angle = self.v.magnitude();
+        let exp_s = self.s.exp();
+        if ulps_eq!(angle, &S::zero()) {
+            Quaternion::from_sv(exp_s, self.v * exp_s)
+        } else {
+            let (sin, cos) = angle.sin_cos();
+            Quaternion::from_sv(exp_s * cos, self.v * (exp_s * sin / angle))
+        }
+    }
+
+    /// The natural logarithm of the quaternion, which must be non-zero.
+    ///
+    /// This is the inverse of `exp`, taking the angle of the vector part to
+    /// be in the range `[0, π]`.
+    pub fn ln(self) -> Quaternion<S> {
+        let magnitude_v = self.v.magnitude();
+        let ln_magnitude = self.magnitude().ln();
+        if ulps_eq!(magnitude_v, &S::zero()) {
+            Quaternion::from_sv(ln_magnitude, Vector3::zero())
+        } else {
+            let angle = magnitude_v.atan2(self.s);
+            Quaternion::from_sv(ln_magnitude, self.v * (angle / magnitude_v))
+        }
+    }
+
+    /// Raise the quaternion to a real power.
+    ///
+    /// For a unit quaternion this scales the angle of the rotation by `n`,
+    /// about the same axis.
+    #[inline]
+    pub fn powf(self, n: S) -> Quaternion<S> {
+        (self.ln() * n).exp()
+    }
+
+    /// Decompose the rotation into a swing and a twist, such that
+    /// `self == swing * twist`.
+    ///
+    /// The twist is a rotation about `axis`, which must be normalized, and the
+    /// swing is a rotation about an axis perpendicular to it. When the
+    /// quaternion rotates `axis` by half a turn the twist is ambiguous, and
+    /// the identity is returned for it.
+    ///
+    /// The quaternion should be normalized. Returns `(swing, twist)`.
+    pub fn swing_twist(self, axis: Vector3<S>) -> (Quaternion<S>, Quaternion<S>) {
+        let projection = Quaternion::from_sv(self.s, axis * self.v.dot(axis));
+        let twist = if ulps_eq!(projection.magnitude2(), &S::zero()) {
+            Quaternion::one()
+        } else {
+            projection.normalize()
+        };
+        (self * twist.conjugate(), twist)
+    }
+
+    /// The signed angle of the twist about `axis` returned by `swing_twist`,
+    /// in the range `[-π, π]`.
+    ///
+    /// For example, the yaw of a camera orientation is its twist angle about
+    /// the up axis.
+    pub fn twist_angle(self, axis: Vector3<S>) -> Rad<S> {
+        let (s, d) = if self.s < S::zero() {
+            (-self.s, -self.v.dot(axis))
+        } else {
+            (self.s, self.v.dot(axis))
+        };
+        let half_angle = d.atan2(s);
+        Rad(half_angle + half_angle)
+    }
+
+    /// Spherical quadrangle interpolation (SQUAD) between the quaternion and
+    /// `other`, using the intermediate control points `self_tangent` and
+    /// `other_tangent`.
+    ///
+    /// When the control points are computed with `squad_tangent`, a spline
+    /// made up of SQUAD segments through a sequence of keyframes has a
+    /// continuous angular velocity. All quaternions should be normalized.
+    ///
+    /// This is the scheme described in Ken Shoemake's "Animating Rotation with
+    /// Quaternion Curves".
+    pub fn squad(self, self_tangent: Quaternion<S>, other_tangent: Quaternion<S>,
+                 other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let two: S = cast(2.0f64).unwrap();
+        let outer = self.slerp(other, amount);
+        let inner = self_tangent.slerp(other_tangent, amount);
+        outer.slerp(inner, two * amount * (S::one() - amount))
+    }
+
+    /// The SQUAD control point for the quaternion, given the keyframes that
+    /// come before and after it.
+    ///
+    /// For the first and last keyframes of a spline, pass the keyframe itself
+    /// as the missing neighbour. The neighbours are flipped into the same
+    /// hemisphere as the quaternion, so all quaternions should be normalized.
+    pub fn squad_tangent(self, prev: Quaternion<S>, next: Quaternion<S>) -> Quaternion<S> {
+        let quarter: S = cast(0.25f64).unwrap();
+        let prev = if self.dot(prev) < S::zero() { -prev } else { prev };
+        let next = if self.dot(next) < S::zero() { -next } else { next };
+        let inverse = self.conjugate();
+        let sum = (inverse * next).ln() + (inverse * prev).ln();
+        self * (sum * -quarter).exp()
+    }
+
+    /// Rotate each normal in `src`, writing the normalized results to `dst`,
+    /// like `Matrix4::transform_normals`.
+    ///
+    /// A rotation does not change the direction of normals relative to their
+    /// surface, so this is `rotate_vectors` followed by normalization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn transform_normals(&self, src: &[Vector3<S>], dst: &mut [Vector3<S>]) {
+        dst.copy_from_slice(src);
+        self.transform_normals_in_place(dst);
+    }
+
+    /// Rotate each normal in a slice in-place, like `transform_normals`.
+    pub fn transform_normals_in_place(&self, normals: &mut [Vector3<S>]) {
+        self.rotate_vectors_in_place(normals);
+        for normal in normals {
+            *normal = normal.normalize();
+        }
+    }
 }
 
 impl<S: BaseFloat> Zero for Quaternion<S> {
@@ -247,33 +440,14 @@ impl<S: NumCast + Copy> Quaternion<S> {
     }
 }
 
-#[cfg(not(feature = "simd"))]
 impl<S: BaseFloat> InnerSpace for Quaternion<S> {
     #[inline]
     fn dot(self, other: Quaternion<S>) -> S {
+        simd_fast_path!(arch::dot(self, other));
         self.s * other.s + self.v.dot(other.v)
     }
 }
 
-#[cfg(feature = "simd")]
-impl<S: BaseFloat> InnerSpace for Quaternion<S> {
-    #[inline]
-    default fn dot(self, other: Quaternion<S>) -> S {
-        self.s * other.s + self.v.dot(other.v)
-    }
-}
-
-#[cfg(feature = "simd")]
-impl InnerSpace for Quaternion<f32> {
-    #[inline]
-    fn dot(self, other: Quaternion<f32>) -> f32 {
-        let lhs: Simdf32x4 = self.into();
-        let rhs: Simdf32x4 = other.into();
-        let r = lhs * rhs;
-        r.extract(0) + r.extract(1) + r.extract(2) + r.extract(3)
-    }
-}
-
 impl<A> From<Euler<A>> for Quaternion<A::Unitless> where
     A: Angle + Into<Rad<<A as Angle>::Unitless>>,
 {
@@ -295,112 +469,42 @@ impl<A> From<Euler<A>> for Quaternion<A::Unitless> where
     }
 }
 
-#[cfg(not(feature = "simd"))]
 impl_operator!(<S: BaseFloat> Neg for Quaternion<S> {
-    fn neg(quat) -> Quaternion<S> {
+    fn neg(quat) -> Quaternion<S> {{
+        simd_fast_path!(arch::neg(&quat.clone()));
         Quaternion::from_sv(-quat.s, -quat.v)
-    }
-});
-
-#[cfg(feature = "simd")]
-impl_operator_default!(<S: BaseFloat> Neg for Quaternion<S> {
-    fn neg(quat) -> Quaternion<S> {
-        Quaternion::from_sv(-quat.s, -quat.v)
-    }
+    }}
 });
 
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Neg for Quaternion<f32> {
-        fn neg(lhs) -> Quaternion<f32> {
-            (-lhs).into()
-        }
-    }
-}
-
-#[cfg(not(feature = "simd"))]
 impl_operator!(<S: BaseFloat> Mul<S> for Quaternion<S> {
-    fn mul(lhs, rhs) -> Quaternion<S> {
+    fn mul(lhs, rhs) -> Quaternion<S> {{
+        simd_fast_path!(arch::mul_scalar(lhs.clone(), rhs));
         Quaternion::from_sv(lhs.s * rhs, lhs.v * rhs)
-    }
-});
-
-#[cfg(feature = "simd")]
-impl_operator_default!(<S: BaseFloat> Mul<S> for Quaternion<S> {
-    fn mul(lhs, rhs) -> Quaternion<S> {
-        Quaternion::from_sv(lhs.s * rhs, lhs.v * rhs)
-    }
+    }}
 });
 
-#[cfg(feature = "simd")]
-impl_operator_simd!{@rs
-    [Simdf32x4]; Mul<f32> for Quaternion<f32> {
-        fn mul(lhs, rhs) -> Quaternion<f32> {
-            (lhs * rhs).into()
-        }
-    }
-}
-
-#[cfg(not(feature = "simd"))]
 impl_assignment_operator!(<S: BaseFloat> MulAssign<S> for Quaternion<S> {
-    fn mul_assign(&mut self, scalar) { self.s *= scalar; self.v *= scalar; }
-});
-
-#[cfg(feature = "simd")]
-impl_assignment_operator_default!(<S: BaseFloat> MulAssign<S> for Quaternion<S> {
-    fn mul_assign(&mut self, scalar) { self.s *= scalar; self.v *= scalar; }
-});
-
-#[cfg(feature = "simd")]
-impl MulAssign<f32> for Quaternion<f32> {
-    fn mul_assign(&mut self, other: f32) {
-        let s: Simdf32x4 = (*self).into();
-        let other = Simdf32x4::splat(other);
-        *self = (s * other).into();
-    }
-}
-
-#[cfg(not(feature = "simd"))]
-impl_operator!(<S: BaseFloat> Div<S> for Quaternion<S> {
-    fn div(lhs, rhs) -> Quaternion<S> {
-        Quaternion::from_sv(lhs.s / rhs, lhs.v / rhs)
+    fn mul_assign(&mut self, scalar) {
+        simd_fast_path!(*self; arch::mul_scalar(*self, scalar));
+        self.s *= scalar;
+        self.v *= scalar;
     }
 });
 
-#[cfg(feature = "simd")]
-impl_operator_default!(<S: BaseFloat> Div<S> for Quaternion<S> {
-    fn div(lhs, rhs) -> Quaternion<S> {
+impl_operator!(<S: BaseFloat> Div<S> for Quaternion<S> {
+    fn div(lhs, rhs) -> Quaternion<S> {{
+        simd_fast_path!(arch::div_scalar(lhs.clone(), rhs));
         Quaternion::from_sv(lhs.s / rhs, lhs.v / rhs)
-    }
+    }}
 });
 
-#[cfg(feature = "simd")]
-impl_operator_simd!{@rs
-    [Simdf32x4]; Div<f32> for Quaternion<f32> {
-        fn div(lhs, rhs) -> Quaternion<f32> {
-            (lhs / rhs).into()
-        }
-    }
-}
-
-#[cfg(not(feature = "simd"))]
 impl_assignment_operator!(<S: BaseFloat> DivAssign<S> for Quaternion<S> {
-    fn div_assign(&mut self, scalar) { self.s /= scalar; self.v /= scalar; }
-});
-
-#[cfg(feature = "simd")]
-impl_assignment_operator_default!(<S: BaseFloat> DivAssign<S> for Quaternion<S> {
-    fn div_assign(&mut self, scalar) { self.s /= scalar; self.v /= scalar; }
-});
-
-#[cfg(feature = "simd")]
-impl DivAssign<f32> for Quaternion<f32> {
-    fn div_assign(&mut self, other: f32) {
-        let s: Simdf32x4 = (*self).into();
-        let other = Simdf32x4::splat(other);
-        *self = (s / other).into();
+    fn div_assign(&mut self, scalar) {
+        simd_fast_path!(*self; arch::div_scalar(*self, scalar));
+        self.s /= scalar;
+        self.v /= scalar;
     }
-}
+});
 
 impl_operator!(<S: BaseFloat> Rem<S> for Quaternion<S> {
     fn rem(lhs, rhs) -> Quaternion<S> {
@@ -421,139 +525,55 @@ impl_operator!(<S: BaseFloat> Mul<Vector3<S> > for Quaternion<S> {
     }}
 });
 
-#[cfg(not(feature = "simd"))]
 impl_operator!(<S: BaseFloat> Add<Quaternion<S> > for Quaternion<S> {
-    fn add(lhs, rhs) -> Quaternion<S> {
+    fn add(lhs, rhs) -> Quaternion<S> {{
+        simd_fast_path!(arch::add(lhs.clone(), rhs.clone()));
         Quaternion::from_sv(lhs.s + rhs.s, lhs.v + rhs.v)
-    }
-});
-
-#[cfg(feature = "simd")]
-impl_operator_default!(<S: BaseFloat> Add<Quaternion<S> > for Quaternion<S> {
-    fn add(lhs, rhs) -> Quaternion<S> {
-        Quaternion::from_sv(lhs.s + rhs.s, lhs.v + rhs.v)
-    }
+    }}
 });
 
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Add<Quaternion<f32>> for Quaternion<f32> {
-        fn add(lhs, rhs) -> Quaternion<f32> {
-            (lhs + rhs).into()
-        }
-    }
-}
-
-#[cfg(not(feature = "simd"))]
 impl_assignment_operator!(<S: BaseFloat> AddAssign<Quaternion<S> > for Quaternion<S> {
-    fn add_assign(&mut self, other) { self.s += other.s; self.v += other.v; }
-});
-
-#[cfg(feature = "simd")]
-impl_assignment_operator_default!(<S: BaseFloat> AddAssign<Quaternion<S> > for Quaternion<S> {
-    fn add_assign(&mut self, other) { self.s += other.s; self.v += other.v; }
-});
-
-#[cfg(feature = "simd")]
-impl AddAssign for Quaternion<f32> {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs: Simdf32x4 = rhs.into();
-        *self = (s + rhs).into();
-    }
-}
-
-#[cfg(not(feature = "simd"))]
-impl_operator!(<S: BaseFloat> Sub<Quaternion<S> > for Quaternion<S> {
-    fn sub(lhs, rhs) -> Quaternion<S> {
-        Quaternion::from_sv(lhs.s - rhs.s, lhs.v - rhs.v)
+    fn add_assign(&mut self, other) {
+        simd_fast_path!(*self; arch::add(*self, other));
+        self.s += other.s;
+        self.v += other.v;
     }
 });
 
-#[cfg(feature = "simd")]
-impl_operator_default!(<S: BaseFloat> Sub<Quaternion<S> > for Quaternion<S> {
-    fn sub(lhs, rhs) -> Quaternion<S> {
+impl_operator!(<S: BaseFloat> Sub<Quaternion<S> > for Quaternion<S> {
+    fn sub(lhs, rhs) -> Quaternion<S> {{
+        simd_fast_path!(arch::sub(lhs.clone(), rhs.clone()));
         Quaternion::from_sv(lhs.s - rhs.s, lhs.v - rhs.v)
-    }
+    }}
 });
 
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Sub<Quaternion<f32>> for Quaternion<f32> {
-        fn sub(lhs, rhs) -> Quaternion<f32> {
-            (lhs - rhs).into()
-        }
-    }
-}
-
-#[cfg(not(feature = "simd"))]
 impl_assignment_operator!(<S: BaseFloat> SubAssign<Quaternion<S> > for Quaternion<S> {
-    fn sub_assign(&mut self, other) { self.s -= other.s; self.v -= other.v; }
-});
-
-#[cfg(feature = "simd")]
-impl_assignment_operator_default!(<S: BaseFloat> SubAssign<Quaternion<S> > for Quaternion<S> {
-    fn sub_assign(&mut self, other) { self.s -= other.s; self.v -= other.v; }
-});
-
-#[cfg(feature = "simd")]
-impl SubAssign for Quaternion<f32> {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs: Simdf32x4 = rhs.into();
-        *self = (s - rhs).into();
-    }
-}
-
-#[cfg(not(feature = "simd"))]
-impl_operator!(<S: BaseFloat> Mul<Quaternion<S> > for Quaternion<S> {
-    fn mul(lhs, rhs) -> Quaternion<S> {
-        Quaternion::new(lhs.s * rhs.s - lhs.v.x * rhs.v.x - lhs.v.y * rhs.v.y - lhs.v.z * rhs.v.z,
-                        lhs.s * rhs.v.x + lhs.v.x * rhs.s + lhs.v.y * rhs.v.z - lhs.v.z * rhs.v.y,
-                        lhs.s * rhs.v.y + lhs.v.y * rhs.s + lhs.v.z * rhs.v.x - lhs.v.x * rhs.v.z,
-                        lhs.s * rhs.v.z + lhs.v.z * rhs.s + lhs.v.x * rhs.v.y - lhs.v.y * rhs.v.x)
+    fn sub_assign(&mut self, other) {
+        simd_fast_path!(*self; arch::sub(*self, other));
+        self.s -= other.s;
+        self.v -= other.v;
     }
 });
 
-#[cfg(feature = "simd")]
-impl_operator_default!(<S: BaseFloat> Mul<Quaternion<S> > for Quaternion<S> {
+impl_operator!(<S: BaseFloat> Mul<Quaternion<S> > for Quaternion<S> {
     fn mul(lhs, rhs) -> Quaternion<S> {
-        Quaternion::new(lhs.s * rhs.s - lhs.v.x * rhs.v.x - lhs.v.y * rhs.v.y - lhs.v.z * rhs.v.z,
-                        lhs.s * rhs.v.x + lhs.v.x * rhs.s + lhs.v.y * rhs.v.z - lhs.v.z * rhs.v.y,
-                        lhs.s * rhs.v.y + lhs.v.y * rhs.s + lhs.v.z * rhs.v.x - lhs.v.x * rhs.v.z,
-                        lhs.s * rhs.v.z + lhs.v.z * rhs.s + lhs.v.x * rhs.v.y - lhs.v.y * rhs.v.x)
-    }
-});
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Mul<Quaternion<f32>> for Quaternion<f32> {
-        fn mul(lhs, rhs) -> Quaternion<f32> {
-            {
-                let p0 = Simdf32x4::splat(lhs.extract(0)) * rhs;
-                let p1 = Simdf32x4::splat(lhs.extract(1)) * Simdf32x4::new(
-                    -rhs.extract(1), rhs.extract(0), -rhs.extract(3), rhs.extract(2)
-                );
-                let p2 = Simdf32x4::splat(lhs.extract(2)) * Simdf32x4::new(
-                    -rhs.extract(2), rhs.extract(3), rhs.extract(0), -rhs.extract(1)
-                );
-                let p3 = Simdf32x4::splat(lhs.extract(3)) * Simdf32x4::new(
-                    -rhs.extract(3), -rhs.extract(2), rhs.extract(1), rhs.extract(0)
-                );
-                (p0 + p1 + p2 + p3).into()
-            }
+        {
+            simd_fast_path!(arch::mul_quaternion(lhs.clone(), rhs.clone()));
+            Quaternion::new(lhs.s * rhs.s - lhs.v.x * rhs.v.x - lhs.v.y * rhs.v.y - lhs.v.z * rhs.v.z,
+                            lhs.s * rhs.v.x + lhs.v.x * rhs.s + lhs.v.y * rhs.v.z - lhs.v.z * rhs.v.y,
+                            lhs.s * rhs.v.y + lhs.v.y * rhs.s + lhs.v.z * rhs.v.x - lhs.v.x * rhs.v.z,
+                            lhs.s * rhs.v.z + lhs.v.z * rhs.s + lhs.v.x * rhs.v.y - lhs.v.y * rhs.v.x)
         }
     }
-}
+});
 
 macro_rules! impl_scalar_mul {
     ($S:ident) => {
         impl_operator!(Mul<Quaternion<$S>> for $S {
-            fn mul(scalar, quat) -> Quaternion<$S> {
+            fn mul(scalar, quat) -> Quaternion<$S> {{
+                simd_fast_path!(arch::scalar_mul(scalar, quat.clone()));
                 Quaternion::from_sv(scalar * quat.s, scalar * quat.v)
-            }
+            }}
         });
     };
 }
@@ -561,9 +581,10 @@ macro_rules! impl_scalar_mul {
 macro_rules! impl_scalar_div {
     ($S:ident) => {
         impl_operator!(Div<Quaternion<$S>> for $S {
-            fn div(scalar, quat) -> Quaternion<$S> {
+            fn div(scalar, quat) -> Quaternion<$S> {{
+                simd_fast_path!(arch::scalar_div(scalar, quat.clone()));
                 Quaternion::from_sv(scalar / quat.s, scalar / quat.v)
-            }
+            }}
         });
     };
 }
@@ -573,7 +594,7 @@ impl_scalar_mul!(f64);
 impl_scalar_div!(f32);
 impl_scalar_div!(f64);
 
-impl<S: BaseFloat> ApproxEq for Quaternion<S> {
+impl<S: BaseFloat> AbsDiffEq for Quaternion<S> {
     type Epsilon = S::Epsilon;
 
     #[inline]
@@ -582,13 +603,16 @@ impl<S: BaseFloat> ApproxEq for Quaternion<S> {
     }
 
     #[inline]
-    fn default_max_relative() -> S::Epsilon {
-        S::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        S::abs_diff_eq(&self.s, &other.s, epsilon) &&
+        Vector3::abs_diff_eq(&self.v, &other.v, epsilon)
     }
+}
 
+impl<S: BaseFloat> RelativeEq for Quaternion<S> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        S::default_max_ulps()
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
     }
 
     #[inline]
@@ -596,6 +620,13 @@ impl<S: BaseFloat> ApproxEq for Quaternion<S> {
         S::relative_eq(&self.s, &other.s, epsilon, max_relative) &&
         Vector3::relative_eq(&self.v, &other.v, epsilon, max_relative)
     }
+}
+
+impl<S: BaseFloat> UlpsEq for Quaternion<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -697,6 +728,30 @@ impl<S: BaseFloat> Rotation<Point3<S>> for Quaternion<S> {
     #[inline]
     fn rotate_vector(&self, vec: Vector3<S>) -> Vector3<S> { self * vec }
 
+    fn rotate_vectors_in_place(&self, vectors: &mut [Vector3<S>]) {
+        #[cfg(feature = "simd")]
+        {
+            if arch::rotate_vectors(self, vectors) {
+                return;
+            }
+        }
+        for vec in vectors {
+            *vec = self * *vec;
+        }
+    }
+
+    fn rotate_points_in_place(&self, points: &mut [Point3<S>]) {
+        #[cfg(feature = "simd")]
+        {
+            if arch::rotate_points(self, points) {
+                return;
+            }
+        }
+        for point in points {
+            *point = Point3::from_vec(self * point.to_vec());
+        }
+    }
+
     #[inline]
     fn invert(&self) -> Quaternion<S> { self.conjugate() / self.magnitude2() }
 }
@@ -819,6 +874,7 @@ index_operators!(S, [S], RangeTo<usize>);
 index_operators!(S, [S], RangeFrom<usize>);
 index_operators!(S, [S], RangeFull);
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Quaternion<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Quaternion<S> {
@@ -0,0 +1,227 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Random sampling of rotations, directions and points.
+//!
+//! Each sampler implements the `Sample` and `IndependentSample` traits from
+//! `rand::distributions`, and draws from a uniform distribution unless noted
+//! otherwise.
+
+use rand::{Rand, Rng};
+use rand::distributions::{IndependentSample, Sample};
+
+use structure::*;
+
+use aabb::{Aabb2, Aabb3};
+use angle::Rad;
+use num::BaseFloat;
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use rotation::Basis3;
+use vector::{Vector2, Vector3};
+
+/// Uniformly distributed rotations.
+///
+/// Unlike generating random `Euler` angles, or normalizing a `Quaternion` with
+/// random components, every orientation is equally likely.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UniformRotation;
+
+/// Unit vectors uniformly distributed around the unit circle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitCircle;
+
+/// Unit vectors uniformly distributed over the surface of the unit sphere.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitSphere;
+
+/// Vectors uniformly distributed inside the unit disk.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitDisk;
+
+/// Vectors uniformly distributed inside the unit ball.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitBall;
+
+/// Unit vectors in the hemisphere around `normal`, distributed with a density
+/// proportional to the cosine of their angle to `normal`.
+///
+/// This is the distribution of light leaving a perfectly diffuse surface,
+/// which makes it useful for importance sampling.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CosineHemisphere<S> {
+    /// The axis of the hemisphere, which must be normalized.
+    pub normal: Vector3<S>,
+}
+
+/// Points uniformly distributed inside the triangle `abc`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle<P> {
+    pub a: P,
+    pub b: P,
+    pub c: P,
+}
+
+impl<S: BaseFloat> CosineHemisphere<S> {
+    /// Construct a sampler for the hemisphere around `normal`.
+    #[inline]
+    pub fn new(normal: Vector3<S>) -> CosineHemisphere<S> {
+        CosineHemisphere { normal }
+    }
+}
+
+impl<P> Triangle<P> {
+    /// Construct a sampler for the triangle `abc`.
+    #[inline]
+    pub fn new(a: P, b: P, c: P) -> Triangle<P> {
+        Triangle { a, b, c }
+    }
+}
+
+/// A point on the unit circle for a random variable in `[0, 1)`.
+#[inline]
+fn circle<S: BaseFloat>(u: S) -> Vector2<S> {
+    let (s, c) = Rad::sin_cos(Rad::full_turn() * u);
+    Vector2::new(c, s)
+}
+
+macro_rules! impl_sample {
+    (<$S:ident> $Sampler:ty => $Support:ty) => {
+        impl<$S: BaseFloat + Rand> Sample<$Support> for $Sampler {
+            #[inline]
+            fn sample<R: Rng>(&mut self, rng: &mut R) -> $Support {
+                self.ind_sample(rng)
+            }
+        }
+    }
+}
+
+impl_sample!(<S> UniformRotation => Quaternion<S>);
+impl_sample!(<S> UniformRotation => Basis3<S>);
+impl_sample!(<S> UnitCircle => Vector2<S>);
+impl_sample!(<S> UnitSphere => Vector3<S>);
+impl_sample!(<S> UnitDisk => Vector2<S>);
+impl_sample!(<S> UnitBall => Vector3<S>);
+impl_sample!(<S> CosineHemisphere<S> => Vector3<S>);
+impl_sample!(<S> Triangle<Point2<S>> => Point2<S>);
+impl_sample!(<S> Triangle<Point3<S>> => Point3<S>);
+impl_sample!(<S> Aabb2<S> => Point2<S>);
+impl_sample!(<S> Aabb3<S> => Point3<S>);
+
+impl<S: BaseFloat + Rand> IndependentSample<Quaternion<S>> for UniformRotation {
+    /// Uses the subgroup algorithm described in Ken Shoemake's "Uniform
+    /// Random Rotations" (Graphics Gems III).
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Quaternion<S> {
+        let u: S = rng.gen();
+        let a = circle(rng.gen()) * (S::one() - u).sqrt();
+        let b = circle(rng.gen()) * u.sqrt();
+        Quaternion::new(b.x, a.y, a.x, b.y)
+    }
+}
+
+impl<S: BaseFloat + Rand> IndependentSample<Basis3<S>> for UniformRotation {
+    #[inline]
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Basis3<S> {
+        let q: Quaternion<S> = self.ind_sample(rng);
+        q.into()
+    }
+}
+
+impl<S: BaseFloat + Rand> IndependentSample<Vector2<S>> for UnitCircle {
+    #[inline]
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Vector2<S> {
+        circle(rng.gen())
+    }
+}
+
+impl<S: BaseFloat + Rand> IndependentSample<Vector3<S>> for UnitSphere {
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Vector3<S> {
+        let u: S = rng.gen();
+        let z = u + u - S::one();
+        let xy = circle(rng.gen()) * (S::one() - z * z).sqrt();
+        xy.extend(z)
+    }
+}
+
+impl<S: BaseFloat + Rand> IndependentSample<Vector2<S>> for UnitDisk {
+    #[inline]
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Vector2<S> {
+        let u: S = rng.gen();
+        circle(rng.gen()) * u.sqrt()
+    }
+}
+
+impl<S: BaseFloat + Rand> IndependentSample<Vector3<S>> for UnitBall {
+    #[inline]
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Vector3<S> {
+        let direction: Vector3<S> = UnitSphere.ind_sample(rng);
+        let u: S = rng.gen();
+        direction * u.cbrt()
+    }
+}
+
+impl<S: BaseFloat + Rand> IndependentSample<Vector3<S>> for CosineHemisphere<S> {
+    /// Projects a uniformly distributed point in the unit disk up onto the
+    /// hemisphere, following Malley's method.
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> Vector3<S> {
+        let disk: Vector2<S> = UnitDisk.ind_sample(rng);
+        let height = (S::one() - disk.magnitude2()).max(S::zero()).sqrt();
+
+        let n = self.normal;
+        let helper = if n.x.abs() > n.y.abs() { Vector3::unit_y() } else { Vector3::unit_x() };
+        let tangent = n.cross(helper).normalize();
+        let bitangent = n.cross(tangent);
+        tangent * disk.x + bitangent * disk.y + n * height
+    }
+}
+
+macro_rules! impl_triangle_sample {
+    ($PointN:ident) => {
+        impl<S: BaseFloat + Rand> IndependentSample<$PointN<S>> for Triangle<$PointN<S>> {
+            fn ind_sample<R: Rng>(&self, rng: &mut R) -> $PointN<S> {
+                let u: S = rng.gen();
+                let v: S = rng.gen();
+                let r = u.sqrt();
+                self.a + (self.b - self.a) * (r * (S::one() - v)) + (self.c - self.a) * (r * v)
+            }
+        }
+    }
+}
+
+impl_triangle_sample!(Point2);
+impl_triangle_sample!(Point3);
+
+macro_rules! impl_aabb_sample {
+    ($AabbN:ident, $PointN:ident, $VectorN:ident) => {
+        impl<S: BaseFloat + Rand> IndependentSample<$PointN<S>> for $AabbN<S> {
+            #[inline]
+            fn ind_sample<R: Rng>(&self, rng: &mut R) -> $PointN<S> {
+                let u: $VectorN<S> = rng.gen();
+                self.min + (self.max - self.min).mul_element_wise(u)
+            }
+        }
+    }
+}
+
+impl_aabb_sample!(Aabb2, Point2, Vector2);
+impl_aabb_sample!(Aabb3, Point3, Vector3);
+
+impl<S: BaseFloat + Rand> Rand for Basis3<S> {
+    /// Generates a uniformly distributed rotation.
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Basis3<S> {
+        UniformRotation.ind_sample(rng)
+    }
+}
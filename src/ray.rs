@@ -0,0 +1,204 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use aabb::Aabb3;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use sphere::Sphere;
+use vector::Vector3;
+
+/// A half-line in three-dimensional space, starting at `origin` and extending
+/// infinitely along `direction`.
+///
+/// The intersection queries return the ray parameter `t` of the hit along
+/// with the hit point, `origin + direction * t`. Only hits with `t >= 0` are
+/// reported. If `direction` is normalized, `t` is the distance from the
+/// origin to the hit point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray3<S> {
+    /// The point the ray starts from.
+    pub origin: Point3<S>,
+    /// The direction the ray extends in.
+    pub direction: Vector3<S>,
+}
+
+impl<S: BaseFloat> Ray3<S> {
+    /// Construct a ray from an origin and a direction.
+    #[inline]
+    pub fn new(origin: Point3<S>, direction: Vector3<S>) -> Ray3<S> {
+        Ray3 { origin, direction }
+    }
+
+    /// The point at parameter `t` along the ray.
+    #[inline]
+    pub fn point_at(&self, t: S) -> Point3<S> {
+        self.origin + self.direction * t
+    }
+
+    #[inline]
+    fn hit(&self, t: S) -> Option<(S, Point3<S>)> {
+        Some((t, self.point_at(t)))
+    }
+
+    /// Find where the ray crosses `plane`. Returns `None` if the ray is
+    /// parallel to the plane or points away from it.
+    pub fn intersect_plane(&self, plane: &Plane<S>) -> Option<(S, Point3<S>)> {
+        let denom = plane.normal.dot(self.direction);
+        let scale = (plane.normal.magnitude2() * self.direction.magnitude2()).sqrt();
+        if denom.abs() <= scale * S::default_epsilon() {
+            return None;
+        }
+
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < S::zero() { None } else { self.hit(t) }
+    }
+
+    /// Find the first point where the ray crosses the surface of `sphere`. If
+    /// the ray starts inside the sphere, this is where it leaves.
+    pub fn intersect_sphere(&self, sphere: &Sphere<S>) -> Option<(S, Point3<S>)> {
+        let oc = self.origin - sphere.center;
+        let a = self.direction.magnitude2();
+        let b = oc.dot(self.direction);
+        let c = oc.magnitude2() - sphere.radius * sphere.radius;
+
+        let discriminant = b * b - a * c;
+        if discriminant < S::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let t0 = (-b - root) / a;
+        let t1 = (-b + root) / a;
+        if t0 >= S::zero() {
+            self.hit(t0)
+        } else if t1 >= S::zero() {
+            self.hit(t1)
+        } else {
+            None
+        }
+    }
+
+    /// Find where the ray crosses the triangle `abc`, from either side, using
+    /// the [Möller–Trumbore] algorithm.
+    ///
+    /// [Möller–Trumbore]: https://en.wikipedia.org/wiki/M%C3%B6ller%E2%80%93Trumbore_intersection_algorithm
+    pub fn intersect_triangle(&self, a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<(S, Point3<S>)> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        let scale = (edge1.magnitude2() * edge2.magnitude2() * self.direction.magnitude2()).sqrt();
+        if det.abs() <= scale * S::default_epsilon() {
+            // The ray is parallel to the plane of the triangle
+            return None;
+        }
+        let inv_det = det.recip();
+
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < S::zero() || u > S::one() {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < S::zero() || u + v > S::one() {
+            return None;
+        }
+
+        let t = edge2.dot(q) * inv_det;
+        if t < S::zero() { None } else { self.hit(t) }
+    }
+
+    /// Find the first point where the ray crosses the surface of `aabb`, using
+    /// the slab method. If the ray starts inside the box, this is where it
+    /// leaves.
+    pub fn intersect_aabb(&self, aabb: &Aabb3<S>) -> Option<(S, Point3<S>)> {
+        let mut t_min = S::neg_infinity();
+        let mut t_max = S::infinity();
+
+        for i in 0..3 {
+            let (origin, direction) = (self.origin[i], self.direction[i]);
+            if direction == S::zero() {
+                // Parallel to this slab, so we either always or never overlap it
+                if origin < aabb.min[i] || origin > aabb.max[i] {
+                    return None;
+                }
+            } else {
+                let inv_direction = direction.recip();
+                let t0 = (aabb.min[i] - origin) * inv_direction;
+                let t1 = (aabb.max[i] - origin) * inv_direction;
+                let (t0, t1) = if t0 > t1 { (t1, t0) } else { (t0, t1) };
+                t_min = t_min.max(t0);
+                t_max = t_max.min(t1);
+            }
+        }
+
+        if t_max < t_min || t_max < S::zero() {
+            None
+        } else if t_min >= S::zero() {
+            self.hit(t_min)
+        } else {
+            self.hit(t_max)
+        }
+    }
+}
+
+impl<S: BaseFloat> AbsDiffEq for Ray3<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Point3::abs_diff_eq(&self.origin, &other.origin, epsilon) &&
+        Vector3::abs_diff_eq(&self.direction, &other.direction, epsilon)
+    }
+}
+
+impl<S: BaseFloat> RelativeEq for Ray3<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.origin, &other.origin, epsilon, max_relative) &&
+        Vector3::relative_eq(&self.direction, &other.direction, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> UlpsEq for Ray3<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.origin, &other.origin, epsilon, max_ulps) &&
+        Vector3::ulps_eq(&self.direction, &other.direction, epsilon, max_ulps)
+    }
+}
@@ -13,14 +13,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
-use std::iter;
-use std::ops::*;
+use core::fmt;
+use core::iter;
+use core::ops::*;
 
 use structure::*;
 
 use angle::Rad;
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use euler::Euler;
 use matrix::{Matrix2, Matrix3};
 use num::BaseFloat;
@@ -32,7 +32,8 @@ use vector::{Vector2, Vector3};
 /// creates a circular motion, and preserves at least one point in the space.
 pub trait Rotation<P: EuclideanSpace>: Sized + Copy + One where
     // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
-    Self: ApproxEq<Epsilon = P::Scalar>,
+    Self: RelativeEq<Epsilon = P::Scalar>,
+    Self: UlpsEq<Epsilon = P::Scalar>,
     P::Scalar: BaseFloat,
     Self: iter::Product<Self>,
 {
@@ -53,6 +54,44 @@ pub trait Rotation<P: EuclideanSpace>: Sized + Copy + One where
         P::from_vec(self.rotate_vector(point.to_vec()))
     }
 
+    /// Rotate each vector in `src`, writing the results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn rotate_vectors(&self, src: &[P::Diff], dst: &mut [P::Diff]) {
+        dst.copy_from_slice(src);
+        self.rotate_vectors_in_place(dst);
+    }
+
+    /// Rotate each vector in a slice, in-place.
+    #[inline]
+    fn rotate_vectors_in_place(&self, vectors: &mut [P::Diff]) {
+        for vec in vectors {
+            *vec = self.rotate_vector(*vec);
+        }
+    }
+
+    /// Rotate each point in `src`, writing the results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn rotate_points(&self, src: &[P], dst: &mut [P]) {
+        dst.copy_from_slice(src);
+        self.rotate_points_in_place(dst);
+    }
+
+    /// Rotate each point in a slice, in-place.
+    #[inline]
+    fn rotate_points_in_place(&self, points: &mut [P]) {
+        for point in points {
+            *point = self.rotate_point(*point);
+        }
+    }
+
     /// Create a new rotation which "un-does" this rotation. That is,
     /// `r * r.invert()` is the identity.
     fn invert(&self) -> Self;
@@ -95,6 +134,24 @@ pub trait Rotation3<S: BaseFloat>: Rotation<Point3<S>>
     fn from_angle_z<A: Into<Rad<S>>>(theta: A) -> Self {
         Rotation3::from_axis_angle(Vector3::unit_z(), theta)
     }
+
+    /// Extract the axis and angle of the rotation, such that passing them to
+    /// `from_axis_angle` recreates it.
+    ///
+    /// The axis is normalized and the angle is in the range `[0, π]`. For a
+    /// rotation by a zero angle the axis is arbitrary, and the `x` axis is
+    /// returned.
+    fn to_axis_angle(self) -> (Vector3<S>, Rad<S>) {
+        let q: Quaternion<S> = self.into();
+        let q = if q.s < S::zero() { -q } else { q };
+        let sin_half_angle = q.v.magnitude();
+        if ulps_eq!(sin_half_angle, &S::zero()) {
+            (Vector3::unit_x(), Rad::zero())
+        } else {
+            let half_angle = sin_half_angle.atan2(q.s);
+            (q.v / sin_half_angle, Rad(half_angle + half_angle))
+        }
+    }
 }
 
 
@@ -116,7 +173,7 @@ pub trait Rotation3<S: BaseFloat>: Rotation<Point3<S>>
 /// use cgmath::Vector2;
 /// use cgmath::{Matrix, Matrix2};
 /// use cgmath::{Rotation, Rotation2, Basis2};
-/// use cgmath::ApproxEq;
+/// use cgmath::UlpsEq;
 /// use std::f64;
 ///
 /// // For simplicity, we will rotate the unit x vector to the unit y vector --
@@ -147,6 +204,14 @@ pub struct Basis2<S> {
     mat: Matrix2<S>
 }
 
+impl<S> Basis2<S> {
+    /// Wrap a matrix that is already known to be a rotation.
+    #[inline]
+    pub(crate) fn from_matrix_unchecked(mat: Matrix2<S>) -> Basis2<S> {
+        Basis2 { mat }
+    }
+}
+
 impl<S: BaseFloat> AsRef<Matrix2<S>> for Basis2<S> {
     #[inline]
     fn as_ref(&self) -> &Matrix2<S> {
@@ -202,7 +267,7 @@ impl_operator!(<S: BaseFloat> Mul<Basis2<S> > for Basis2<S> {
     fn mul(lhs, rhs) -> Basis2<S> { Basis2 { mat: lhs.mat * rhs.mat  } }
 });
 
-impl<S: BaseFloat> ApproxEq for Basis2<S> {
+impl<S: BaseFloat> AbsDiffEq for Basis2<S> {
     type Epsilon = S::Epsilon;
 
     #[inline]
@@ -211,19 +276,28 @@ impl<S: BaseFloat> ApproxEq for Basis2<S> {
     }
 
     #[inline]
-    fn default_max_relative() -> S::Epsilon {
-        S::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Matrix2::abs_diff_eq(&self.mat, &other.mat, epsilon)
     }
+}
 
+impl<S: BaseFloat> RelativeEq for Basis2<S> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        S::default_max_ulps()
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
     }
 
     #[inline]
     fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
         Matrix2::relative_eq(&self.mat, &other.mat, epsilon, max_relative)
     }
+}
+
+impl<S: BaseFloat> UlpsEq for Basis2<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -260,6 +334,35 @@ impl<S: BaseFloat> Basis3<S> {
     pub fn from_quaternion(quaternion: &Quaternion<S>) -> Basis3<S> {
         Basis3 { mat: quaternion.clone().into() }
     }
+
+    /// Wrap a matrix that is already known to be a rotation.
+    #[inline]
+    pub(crate) fn from_matrix_unchecked(mat: Matrix3<S>) -> Basis3<S> {
+        Basis3 { mat }
+    }
+
+    /// The mean of a set of rotations, or `None` if `rotations` is empty.
+    ///
+    /// See `Quaternion::weighted_mean` for details.
+    #[inline]
+    pub fn mean(rotations: &[Basis3<S>]) -> Option<Basis3<S>> {
+        let weighted = rotations.iter().map(|&r| (r.into(), S::one()));
+        Quaternion::mean_of(weighted).map(|q| Basis3::from_quaternion(&q))
+    }
+
+    /// The weighted mean of a set of rotations, or `None` if there are no
+    /// rotations or the weights sum to zero.
+    ///
+    /// See `Quaternion::weighted_mean` for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rotations` and `weights` have different lengths.
+    pub fn weighted_mean(rotations: &[Basis3<S>], weights: &[S]) -> Option<Basis3<S>> {
+        assert_eq!(rotations.len(), weights.len());
+        let weighted = rotations.iter().zip(weights).map(|(&r, &w)| (r.into(), w));
+        Quaternion::mean_of(weighted).map(|q| Basis3::from_quaternion(&q))
+    }
 }
 
 impl<S> AsRef<Matrix3<S>> for Basis3<S> {
@@ -323,7 +426,7 @@ impl_operator!(<S: BaseFloat> Mul<Basis3<S> > for Basis3<S> {
     fn mul(lhs, rhs) -> Basis3<S> { Basis3 { mat: lhs.mat * rhs.mat  } }
 });
 
-impl<S: BaseFloat> ApproxEq for Basis3<S> {
+impl<S: BaseFloat> AbsDiffEq for Basis3<S> {
     type Epsilon = S::Epsilon;
 
     #[inline]
@@ -332,19 +435,28 @@ impl<S: BaseFloat> ApproxEq for Basis3<S> {
     }
 
     #[inline]
-    fn default_max_relative() -> S::Epsilon {
-        S::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Matrix3::abs_diff_eq(&self.mat, &other.mat, epsilon)
     }
+}
 
+impl<S: BaseFloat> RelativeEq for Basis3<S> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        S::default_max_ulps()
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
     }
 
     #[inline]
     fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
         Matrix3::relative_eq(&self.mat, &other.mat, epsilon, max_relative)
     }
+}
+
+impl<S: BaseFloat> UlpsEq for Basis3<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -0,0 +1,92 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::BaseFloat;
+use point::Point3;
+
+/// A sphere in three-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere<S> {
+    /// The center of the sphere.
+    pub center: Point3<S>,
+    /// The radius of the sphere.
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    /// Construct a sphere from a center point and a radius.
+    #[inline]
+    pub fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere { center, radius }
+    }
+
+    /// Returns `true` if `point` lies inside or on the surface of the sphere.
+    #[inline]
+    pub fn contains_point(&self, point: Point3<S>) -> bool {
+        self.center.distance2(point) <= self.radius * self.radius
+    }
+
+    /// Returns `true` if this sphere and `other` overlap.
+    #[inline]
+    pub fn intersects(&self, other: &Sphere<S>) -> bool {
+        let radii = self.radius + other.radius;
+        self.center.distance2(other.center) <= radii * radii
+    }
+}
+
+impl<S: BaseFloat> AbsDiffEq for Sphere<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Point3::abs_diff_eq(&self.center, &other.center, epsilon) &&
+        S::abs_diff_eq(&self.radius, &other.radius, epsilon)
+    }
+}
+
+impl<S: BaseFloat> RelativeEq for Sphere<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.center, &other.center, epsilon, max_relative) &&
+        S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> UlpsEq for Sphere<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.center, &other.center, epsilon, max_ulps) &&
+        S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+    }
+}
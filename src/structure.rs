@@ -16,11 +16,11 @@
 //! Generic algebraic structures
 
 use num_traits::{cast, Float};
-use std::cmp;
-use std::iter;
-use std::ops::*;
+use core::cmp;
+use core::iter;
+use core::ops::*;
 
-use approx::ApproxEq;
+use approx::{RelativeEq, UlpsEq};
 
 use angle::Rad;
 use num::{BaseNum, BaseFloat};
@@ -71,7 +71,7 @@ pub trait Array where
     /// Swap the elements at indices `i` and `j` in-place.
     #[inline]
     fn swap_elements(&mut self, i: usize, j: usize) {
-        use std::ptr;
+        use core::ptr;
 
         // Yeah, ok borrow checker – I know what I'm doing here
         unsafe { ptr::swap(&mut self[i], &mut self[j]) };
@@ -203,7 +203,8 @@ pub trait InnerSpace: VectorSpace where
     // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
     <Self as VectorSpace>::Scalar: BaseFloat,
     Self: MetricSpace<Metric = <Self as VectorSpace>::Scalar>,
-    Self: ApproxEq<Epsilon = <Self as VectorSpace>::Scalar>,
+    Self: RelativeEq<Epsilon = <Self as VectorSpace>::Scalar>,
+    Self: UlpsEq<Epsilon = <Self as VectorSpace>::Scalar>,
 {
     /// Vector dot (or inner) product.
     fn dot(self, other: Self) -> Self::Scalar;
@@ -421,7 +422,8 @@ pub trait Matrix: VectorSpace where
     // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
     Self: Index<usize, Output = <Self as Matrix>::Column>,
     Self: IndexMut<usize, Output = <Self as Matrix>::Column>,
-    Self: ApproxEq<Epsilon = <Self as VectorSpace>::Scalar>,
+    Self: RelativeEq<Epsilon = <Self as VectorSpace>::Scalar>,
+    Self: UlpsEq<Epsilon = <Self as VectorSpace>::Scalar>,
 {
     /// The row vector of the matrix.
     type Row: VectorSpace<Scalar = Self::Scalar> + Array<Element = Self::Scalar>;
@@ -447,7 +449,7 @@ pub trait Matrix: VectorSpace where
     /// Replace a column in the array.
     #[inline]
     fn replace_col(&mut self, c: usize, src: Self::Column) -> Self::Column {
-        use std::mem;
+        use core::mem;
 
         mem::replace(&mut self[c], src)
     }
@@ -554,7 +556,8 @@ pub trait Angle where
     Self: Copy + Clone,
     Self: PartialEq + cmp::PartialOrd,
     // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
-    Self: ApproxEq<Epsilon = <Self as Angle>::Unitless>,
+    Self: RelativeEq<Epsilon = <Self as Angle>::Unitless>,
+    Self: UlpsEq<Epsilon = <Self as Angle>::Unitless>,
 
     Self: Zero,
 
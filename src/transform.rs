@@ -15,7 +15,7 @@
 
 use structure::*;
 
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use matrix::{Matrix2, Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
@@ -45,6 +45,44 @@ pub trait Transform<P: EuclideanSpace>: Sized {
     /// Transform a point using this transform.
     fn transform_point(&self, point: P) -> P;
 
+    /// Transform each point in `src`, writing the results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn transform_points(&self, src: &[P], dst: &mut [P]) {
+        dst.copy_from_slice(src);
+        self.transform_points_in_place(dst);
+    }
+
+    /// Transform each point in a slice, in-place.
+    #[inline]
+    fn transform_points_in_place(&self, points: &mut [P]) {
+        for point in points {
+            *point = self.transform_point(*point);
+        }
+    }
+
+    /// Transform each vector in `src`, writing the results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn transform_vectors(&self, src: &[P::Diff], dst: &mut [P::Diff]) {
+        dst.copy_from_slice(src);
+        self.transform_vectors_in_place(dst);
+    }
+
+    /// Transform each vector in a slice, in-place.
+    #[inline]
+    fn transform_vectors_in_place(&self, vectors: &mut [P::Diff]) {
+        for vec in vectors {
+            *vec = self.transform_vector(*vec);
+        }
+    }
+
     /// Combine this transform with another, yielding a new transformation
     /// which has the effects of both.
     fn concat(&self, other: &Self) -> Self;
@@ -61,7 +99,7 @@ pub trait Transform<P: EuclideanSpace>: Sized {
 
 /// A generic transformation consisting of a rotation,
 /// displacement vector and scale amount.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Decomposed<V: VectorSpace, R> {
     pub scale: V::Scalar,
     pub rot: R,
@@ -112,6 +150,25 @@ impl<P: EuclideanSpace, R: Rotation<P>> Transform<P> for Decomposed<P::Diff, R>
         self.rot.rotate_point(point * self.scale) + self.disp
     }
 
+    /// Applies the scale, rotation and displacement to the whole slice in
+    /// turn, so that the rotation can use its own batch implementation.
+    fn transform_points_in_place(&self, points: &mut [P]) {
+        for point in points.iter_mut() {
+            *point = *point * self.scale;
+        }
+        self.rot.rotate_points_in_place(points);
+        for point in points.iter_mut() {
+            *point = *point + self.disp;
+        }
+    }
+
+    fn transform_vectors_in_place(&self, vectors: &mut [P::Diff]) {
+        for vec in vectors.iter_mut() {
+            *vec = *vec * self.scale;
+        }
+        self.rot.rotate_vectors_in_place(vectors);
+    }
+
     fn concat(&self, other: &Decomposed<P::Diff, R>) -> Decomposed<P::Diff, R> {
         Decomposed {
             scale: self.scale * other.scale,
@@ -136,6 +193,32 @@ impl<P: EuclideanSpace, R: Rotation<P>> Transform<P> for Decomposed<P::Diff, R>
     }
 }
 
+impl<S: BaseFloat, R: Rotation3<S>> Decomposed<Vector3<S>, R> {
+    /// Transform each normal in `src`, writing the normalized results to
+    /// `dst`, like `Matrix4::transform_normals`.
+    ///
+    /// A uniform scale does not change the direction of normals, so they are
+    /// only rotated, and flipped if the scale is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn transform_normals(&self, src: &[Vector3<S>], dst: &mut [Vector3<S>]) {
+        dst.copy_from_slice(src);
+        self.transform_normals_in_place(dst);
+    }
+
+    /// Transform each normal in a slice in-place, like `transform_normals`.
+    pub fn transform_normals_in_place(&self, normals: &mut [Vector3<S>]) {
+        self.rot.rotate_vectors_in_place(normals);
+        let sign = if self.scale < S::zero() { -S::one() } else { S::one() };
+        for normal in normals {
+            *normal = normal.normalize() * sign;
+        }
+    }
+}
+
 pub trait Transform2<S: BaseNum>: Transform<Point2<S>> + Into<Matrix3<S>> {}
 pub trait Transform3<S: BaseNum>: Transform<Point3<S>> + Into<Matrix4<S>> {}
 
@@ -161,10 +244,10 @@ impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for Decomposed<Vector2<S>, R>
 
 impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for Decomposed<Vector3<S>, R> {}
 
-impl<S: VectorSpace, R, E: BaseFloat> ApproxEq for Decomposed<S, R>
-    where S: ApproxEq<Epsilon = E>,
-          S::Scalar: ApproxEq<Epsilon = E>,
-          R: ApproxEq<Epsilon = E>
+impl<S: VectorSpace, R, E: BaseFloat> AbsDiffEq for Decomposed<S, R>
+    where S: AbsDiffEq<Epsilon = E>,
+          S::Scalar: AbsDiffEq<Epsilon = E>,
+          R: AbsDiffEq<Epsilon = E>
 {
     type Epsilon = E;
 
@@ -174,13 +257,21 @@ impl<S: VectorSpace, R, E: BaseFloat> ApproxEq for Decomposed<S, R>
     }
 
     #[inline]
-    fn default_max_relative() -> E {
-        E::default_max_relative()
+    fn abs_diff_eq(&self, other: &Self, epsilon: E) -> bool {
+        S::Scalar::abs_diff_eq(&self.scale, &other.scale, epsilon) &&
+        R::abs_diff_eq(&self.rot, &other.rot, epsilon) &&
+        S::abs_diff_eq(&self.disp, &other.disp, epsilon)
     }
+}
 
+impl<S: VectorSpace, R, E: BaseFloat> RelativeEq for Decomposed<S, R>
+    where S: RelativeEq<Epsilon = E>,
+          S::Scalar: RelativeEq<Epsilon = E>,
+          R: RelativeEq<Epsilon = E>
+{
     #[inline]
-    fn default_max_ulps() -> u32 {
-        E::default_max_ulps()
+    fn default_max_relative() -> E {
+        E::default_max_relative()
     }
 
     #[inline]
@@ -189,6 +280,17 @@ impl<S: VectorSpace, R, E: BaseFloat> ApproxEq for Decomposed<S, R>
         R::relative_eq(&self.rot, &other.rot, epsilon, max_relative) &&
         S::relative_eq(&self.disp, &other.disp, epsilon, max_relative)
     }
+}
+
+impl<S: VectorSpace, R, E: BaseFloat> UlpsEq for Decomposed<S, R>
+    where S: UlpsEq<Epsilon = E>,
+          S::Scalar: UlpsEq<Epsilon = E>,
+          R: UlpsEq<Epsilon = E>
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        E::default_max_ulps()
+    }
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: E, max_ulps: u32) -> bool {
@@ -198,6 +300,185 @@ impl<S: VectorSpace, R, E: BaseFloat> ApproxEq for Decomposed<S, R>
     }
 }
 
+/// A generic transformation consisting of a rotation, displacement vector and
+/// a separate scale along each axis.
+///
+/// Points are scaled in their local axes before being rotated and displaced.
+/// The composition of two such transformations cannot always be represented
+/// in this form, so `concat` and `inverse_transform` are only exact when the
+/// scale is uniform, or when the rotations involved keep the scaled axes
+/// aligned. Otherwise they approximate the result by composing the rotations
+/// and scales separately, in the same way as most scene graphs.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NonUniformDecomposed<V, R> {
+    pub scale: V,
+    pub rot: R,
+    pub disp: V,
+}
+
+impl<P: EuclideanSpace, R: Rotation<P>> Transform<P> for NonUniformDecomposed<P::Diff, R>
+    where P::Scalar: BaseFloat,
+          P::Diff: VectorSpace + ElementWise + Array<Element = P::Scalar>
+{
+    #[inline]
+    fn one() -> NonUniformDecomposed<P::Diff, R> {
+        NonUniformDecomposed {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot: R::one(),
+            disp: P::Diff::zero(),
+        }
+    }
+
+    #[inline]
+    fn look_at(eye: P, center: P, up: P::Diff) -> NonUniformDecomposed<P::Diff, R> {
+        let rot = R::look_at(center - eye, up);
+        let disp = rot.rotate_vector(P::origin() - eye);
+        NonUniformDecomposed {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot,
+            disp,
+        }
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: P::Diff) -> P::Diff {
+        self.rot.rotate_vector(vec.mul_element_wise(self.scale))
+    }
+
+    #[inline]
+    fn inverse_transform_vector(&self, vec: P::Diff) -> Option<P::Diff> {
+        if self.has_zero_scale() {
+            None
+        } else {
+            Some(self.rot.invert().rotate_vector(vec).div_element_wise(self.scale))
+        }
+    }
+
+    #[inline]
+    fn transform_point(&self, point: P) -> P {
+        P::from_vec(self.transform_vector(point.to_vec()) + self.disp)
+    }
+
+    fn concat(&self, other: &NonUniformDecomposed<P::Diff, R>) -> NonUniformDecomposed<P::Diff, R> {
+        NonUniformDecomposed {
+            scale: self.scale.mul_element_wise(other.scale),
+            rot: self.rot * other.rot,
+            disp: self.transform_vector(other.disp) + self.disp,
+        }
+    }
+
+    fn inverse_transform(&self) -> Option<NonUniformDecomposed<P::Diff, R>> {
+        if self.has_zero_scale() {
+            return None;
+        }
+
+        // Chosen so that `inverse.transform_point(self.transform_point(P::origin()))`
+        // is exactly the origin
+        let scale = P::Diff::from_value(P::Scalar::one()).div_element_wise(self.scale);
+        let rot = self.rot.invert();
+        let disp = rot.rotate_vector(self.disp.mul_element_wise(scale)) * -P::Scalar::one();
+        Some(NonUniformDecomposed { scale, rot, disp })
+    }
+}
+
+impl<V, R> NonUniformDecomposed<V, R>
+    where V: Array,
+          V::Element: BaseFloat
+{
+    fn has_zero_scale(&self) -> bool {
+        (0..V::len()).any(|i| ulps_eq!(self.scale[i], &V::Element::zero()))
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<S>> From<NonUniformDecomposed<Vector2<S>, R>> for Matrix3<S> {
+    fn from(dec: NonUniformDecomposed<Vector2<S>, R>) -> Matrix3<S> {
+        let m: Matrix2<_> = dec.rot.into();
+        Matrix3::from_cols((m.x * dec.scale.x).extend(S::zero()),
+                           (m.y * dec.scale.y).extend(S::zero()),
+                           dec.disp.extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> From<NonUniformDecomposed<Vector3<S>, R>> for Matrix4<S> {
+    fn from(dec: NonUniformDecomposed<Vector3<S>, R>) -> Matrix4<S> {
+        let m: Matrix3<_> = dec.rot.into();
+        Matrix4::from_cols((m.x * dec.scale.x).extend(S::zero()),
+                           (m.y * dec.scale.y).extend(S::zero()),
+                           (m.z * dec.scale.z).extend(S::zero()),
+                           dec.disp.extend(S::one()))
+    }
+}
+
+impl<V, R> From<Decomposed<V, R>> for NonUniformDecomposed<V, R>
+    where V: VectorSpace + Array<Element = <V as VectorSpace>::Scalar>
+{
+    fn from(dec: Decomposed<V, R>) -> NonUniformDecomposed<V, R> {
+        NonUniformDecomposed {
+            scale: V::from_value(dec.scale),
+            rot: dec.rot,
+            disp: dec.disp,
+        }
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for NonUniformDecomposed<Vector2<S>, R> {}
+
+impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for NonUniformDecomposed<Vector3<S>, R> {}
+
+impl<V, R, E: BaseFloat> AbsDiffEq for NonUniformDecomposed<V, R>
+    where V: AbsDiffEq<Epsilon = E>,
+          R: AbsDiffEq<Epsilon = E>
+{
+    type Epsilon = E;
+
+    #[inline]
+    fn default_epsilon() -> E {
+        E::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: E) -> bool {
+        V::abs_diff_eq(&self.scale, &other.scale, epsilon) &&
+        R::abs_diff_eq(&self.rot, &other.rot, epsilon) &&
+        V::abs_diff_eq(&self.disp, &other.disp, epsilon)
+    }
+}
+
+impl<V, R, E: BaseFloat> RelativeEq for NonUniformDecomposed<V, R>
+    where V: RelativeEq<Epsilon = E>,
+          R: RelativeEq<Epsilon = E>
+{
+    #[inline]
+    fn default_max_relative() -> E {
+        E::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: E, max_relative: E) -> bool {
+        V::relative_eq(&self.scale, &other.scale, epsilon, max_relative) &&
+        R::relative_eq(&self.rot, &other.rot, epsilon, max_relative) &&
+        V::relative_eq(&self.disp, &other.disp, epsilon, max_relative)
+    }
+}
+
+impl<V, R, E: BaseFloat> UlpsEq for NonUniformDecomposed<V, R>
+    where V: UlpsEq<Epsilon = E>,
+          R: UlpsEq<Epsilon = E>
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        E::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: E, max_ulps: u32) -> bool {
+        V::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps) &&
+        R::ulps_eq(&self.rot, &other.rot, epsilon, max_ulps) &&
+        V::ulps_eq(&self.disp, &other.disp, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 mod serde_ser {
@@ -229,8 +510,8 @@ mod serde_de {
     use structure::VectorSpace;
     use super::Decomposed;
     use serde::{self, Deserialize};
-    use std::marker::PhantomData;
-    use std::fmt;
+    use core::marker::PhantomData;
+    use core::fmt;
 
     enum DecomposedField {
         Scale,
@@ -13,25 +13,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::{NumCast, Bounded};
-use std::fmt;
-use std::iter;
-use std::mem;
-use std::ops::*;
+use core::fmt;
+use core::iter;
+use core::mem;
+use core::ops::*;
 
 use structure::*;
 
 use angle::Rad;
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num::{BaseNum, BaseFloat};
 
 #[cfg(feature = "simd")]
-use simd::f32x4 as Simdf32x4;
-#[cfg(feature = "simd")]
-use simd::i32x4 as Simdi32x4;
-#[cfg(feature = "simd")]
-use simd::u32x4 as Simdu32x4;
+use arch;
 
 #[cfg(feature = "mint")]
 use mint;
@@ -123,238 +120,98 @@ macro_rules! impl_vector {
             }
         }
 
-        impl<S: BaseFloat> MetricSpace for $VectorN<S> {
-            type Metric = S;
-
+        impl<S: PartialEq> $VectorN<S> {
+            /// Component-wise `==`, returning a mask of the results.
             #[inline]
-            fn distance2(self, other: Self) -> S {
-                (other - self).magnitude2()
-            }
-        }
-
-        impl<S: Copy> Array for $VectorN<S> {
-            type Element = S;
-
-            #[inline]
-            fn len() -> usize {
-                $n
-            }
-
-            #[inline]
-            fn from_value(scalar: S) -> $VectorN<S> {
-                $VectorN { $($field: scalar),+ }
-            }
-
-            #[inline]
-            fn sum(self) -> S where S: Add<Output = S> {
-                fold_array!(add, { $(self.$field),+ })
+            pub fn eq_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field == other.$field),+)
             }
 
+            /// Component-wise `!=`, returning a mask of the results.
             #[inline]
-            fn product(self) -> S where S: Mul<Output = S> {
-                fold_array!(mul, { $(self.$field),+ })
+            pub fn ne_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field != other.$field),+)
             }
         }
 
-        impl<S: BaseNum> Zero for $VectorN<S> {
+        impl<S: PartialOrd> $VectorN<S> {
+            /// Component-wise `<`, returning a mask of the results.
             #[inline]
-            fn zero() -> $VectorN<S> {
-                $VectorN::from_value(S::zero())
+            pub fn lt_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field < other.$field),+)
             }
 
+            /// Component-wise `<=`, returning a mask of the results.
             #[inline]
-            fn is_zero(&self) -> bool {
-                *self == $VectorN::zero()
+            pub fn le_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field <= other.$field),+)
             }
-        }
 
-        impl<S: BaseNum> iter::Sum<$VectorN<S>> for $VectorN<S> {
+            /// Component-wise `>`, returning a mask of the results.
             #[inline]
-            fn sum<I: Iterator<Item=$VectorN<S>>>(iter: I) -> $VectorN<S> {
-                iter.fold($VectorN::zero(), Add::add)
+            pub fn gt_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field > other.$field),+)
             }
-        }
 
-        impl<'a, S: 'a + BaseNum> iter::Sum<&'a $VectorN<S>> for $VectorN<S> {
+            /// Component-wise `>=`, returning a mask of the results.
             #[inline]
-            fn sum<I: Iterator<Item=&'a $VectorN<S>>>(iter: I) -> $VectorN<S> {
-                iter.fold($VectorN::zero(), Add::add)
+            pub fn ge_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field >= other.$field),+)
             }
         }
 
-        impl<S: BaseNum> VectorSpace for $VectorN<S> {
-            type Scalar = S;
-        }
-
-        impl<S: Neg<Output = S>> Neg for $VectorN<S> {
-            type Output = $VectorN<S>;
-
+        impl $VectorN<bool> {
+            /// Whether any component of the mask is `true`.
             #[inline]
-            fn neg(self) -> $VectorN<S> { $VectorN::new($(-self.$field),+) }
-        }
-
-        impl<S: BaseFloat> ApproxEq for $VectorN<S> {
-            type Epsilon = S::Epsilon;
-
-            #[inline]
-            fn default_epsilon() -> S::Epsilon {
-                S::default_epsilon()
+            pub fn any(self) -> bool {
+                $(self.$field)||+
             }
 
+            /// Whether every component of the mask is `true`.
             #[inline]
-            fn default_max_relative() -> S::Epsilon {
-                S::default_max_relative()
+            pub fn all(self) -> bool {
+                $(self.$field)&&+
             }
 
+            /// Take each component from `if_true` where the mask is `true`, and
+            /// from `if_false` where it is `false`.
             #[inline]
-            fn default_max_ulps() -> u32 {
-                S::default_max_ulps()
+            pub fn select<S>(self, if_true: $VectorN<S>, if_false: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(if self.$field { if_true.$field } else { if_false.$field }),+)
             }
+        }
 
-            #[inline]
-            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
-                $(S::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
-            }
+        impl Not for $VectorN<bool> {
+            type Output = $VectorN<bool>;
 
             #[inline]
-            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
-                $(S::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
-            }
+            fn not(self) -> $VectorN<bool> { $VectorN::new($(!self.$field),+) }
         }
 
-        impl<S: BaseFloat + Rand> Rand for $VectorN<S> {
-            #[inline]
-            fn rand<R: Rng>(rng: &mut R) -> $VectorN<S> {
-                $VectorN { $($field: rng.gen()),+ }
-            }
-        }
+        impl BitAnd for $VectorN<bool> {
+            type Output = $VectorN<bool>;
 
-        impl<S: Bounded> Bounded for $VectorN<S> {
             #[inline]
-            fn min_value() -> $VectorN<S> {
-                $VectorN { $($field: S::min_value()),+ }
-            }
-
-            #[inline]
-            fn max_value() -> $VectorN<S> {
-                $VectorN { $($field: S::max_value()),+ }
+            fn bitand(self, other: $VectorN<bool>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field & other.$field),+)
             }
         }
 
-        impl_operator!(<S: BaseNum> Add<$VectorN<S> > for $VectorN<S> {
-            fn add(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field + rhs.$field),+) }
-        });
-        impl_assignment_operator!(<S: BaseNum> AddAssign<$VectorN<S> > for $VectorN<S> {
-            fn add_assign(&mut self, other) { $(self.$field += other.$field);+ }
-        });
-
-        impl_operator!(<S: BaseNum> Sub<$VectorN<S> > for $VectorN<S> {
-            fn sub(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field - rhs.$field),+) }
-        });
-        impl_assignment_operator!(<S: BaseNum> SubAssign<$VectorN<S> > for $VectorN<S> {
-            fn sub_assign(&mut self, other) { $(self.$field -= other.$field);+ }
-        });
-
-        impl_operator!(<S: BaseNum> Mul<S> for $VectorN<S> {
-            fn mul(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field * scalar),+) }
-        });
-        impl_assignment_operator!(<S: BaseNum> MulAssign<S> for $VectorN<S> {
-            fn mul_assign(&mut self, scalar) { $(self.$field *= scalar);+ }
-        });
-
-        impl_operator!(<S: BaseNum> Div<S> for $VectorN<S> {
-            fn div(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field / scalar),+) }
-        });
-        impl_assignment_operator!(<S: BaseNum> DivAssign<S> for $VectorN<S> {
-            fn div_assign(&mut self, scalar) { $(self.$field /= scalar);+ }
-        });
-
-        impl_operator!(<S: BaseNum> Rem<S> for $VectorN<S> {
-            fn rem(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field % scalar),+) }
-        });
-        impl_assignment_operator!(<S: BaseNum> RemAssign<S> for $VectorN<S> {
-            fn rem_assign(&mut self, scalar) { $(self.$field %= scalar);+ }
-        });
-
-        impl<S: BaseNum> ElementWise for $VectorN<S> {
-            #[inline] fn add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field + rhs.$field),+) }
-            #[inline] fn sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field - rhs.$field),+) }
-            #[inline] fn mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field * rhs.$field),+) }
-            #[inline] fn div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field / rhs.$field),+) }
-            #[inline] fn rem_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field % rhs.$field),+) }
-
-            #[inline] fn add_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field += rhs.$field);+ }
-            #[inline] fn sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field -= rhs.$field);+ }
-            #[inline] fn mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field *= rhs.$field);+ }
-            #[inline] fn div_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field /= rhs.$field);+ }
-            #[inline] fn rem_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field %= rhs.$field);+ }
-        }
-
-        impl<S: BaseNum> ElementWise<S> for $VectorN<S> {
-            #[inline] fn add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) }
-            #[inline] fn sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) }
-            #[inline] fn mul_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field * rhs),+) }
-            #[inline] fn div_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field / rhs),+) }
-            #[inline] fn rem_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field % rhs),+) }
-
-            #[inline] fn add_assign_element_wise(&mut self, rhs: S) { $(self.$field += rhs);+ }
-            #[inline] fn sub_assign_element_wise(&mut self, rhs: S) { $(self.$field -= rhs);+ }
-            #[inline] fn mul_assign_element_wise(&mut self, rhs: S) { $(self.$field *= rhs);+ }
-            #[inline] fn div_assign_element_wise(&mut self, rhs: S) { $(self.$field /= rhs);+ }
-            #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { $(self.$field %= rhs);+ }
-        }
-
-        impl_scalar_ops!($VectorN<usize> { $($field),+ });
-        impl_scalar_ops!($VectorN<u8> { $($field),+ });
-        impl_scalar_ops!($VectorN<u16> { $($field),+ });
-        impl_scalar_ops!($VectorN<u32> { $($field),+ });
-        impl_scalar_ops!($VectorN<u64> { $($field),+ });
-        impl_scalar_ops!($VectorN<isize> { $($field),+ });
-        impl_scalar_ops!($VectorN<i8> { $($field),+ });
-        impl_scalar_ops!($VectorN<i16> { $($field),+ });
-        impl_scalar_ops!($VectorN<i32> { $($field),+ });
-        impl_scalar_ops!($VectorN<i64> { $($field),+ });
-        impl_scalar_ops!($VectorN<f32> { $($field),+ });
-        impl_scalar_ops!($VectorN<f64> { $($field),+ });
-
-        impl_index_operators!($VectorN<S>, $n, S, usize);
-        impl_index_operators!($VectorN<S>, $n, [S], Range<usize>);
-        impl_index_operators!($VectorN<S>, $n, [S], RangeTo<usize>);
-        impl_index_operators!($VectorN<S>, $n, [S], RangeFrom<usize>);
-        impl_index_operators!($VectorN<S>, $n, [S], RangeFull);
-    }
-}
+        impl BitOr for $VectorN<bool> {
+            type Output = $VectorN<bool>;
 
-// Utility macro for generating associated functions for the vectors
-// mainly duplication
-#[cfg(feature = "simd")]
-macro_rules! impl_vector_default {
-    ($VectorN:ident { $($field:ident),+ }, $n:expr, $constructor:ident) => {
-        impl<S> $VectorN<S> {
-            /// Construct a new vector, using the provided values.
             #[inline]
-            pub fn new($($field: S),+) -> $VectorN<S> {
-                $VectorN { $($field: $field),+ }
+            fn bitor(self, other: $VectorN<bool>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field | other.$field),+)
             }
         }
 
-        /// The short constructor.
-        #[inline]
-        pub fn $constructor<S>($($field: S),+) -> $VectorN<S> {
-            $VectorN::new($($field),+)
-        }
+        impl BitXor for $VectorN<bool> {
+            type Output = $VectorN<bool>;
 
-        impl<S: NumCast + Copy> $VectorN<S> {
-            /// Component-wise casting to another type.
             #[inline]
-            pub fn cast<T: NumCast>(&self) -> Option<$VectorN<T>> {
-                $(
-                    let $field = match NumCast::from(self.$field) {
-                        Some(field) => field,
-                        None => return None
-                    };
-                )+
-                Some($VectorN { $($field),+ })
+            fn bitxor(self, other: $VectorN<bool>) -> $VectorN<bool> {
+                $VectorN::new($(self.$field ^ other.$field),+)
             }
         }
 
@@ -403,17 +260,17 @@ macro_rules! impl_vector_default {
             }
         }
 
-        impl<S: BaseNum> iter::Sum for $VectorN<S> {
+        impl<S: BaseNum> iter::Sum<$VectorN<S>> for $VectorN<S> {
             #[inline]
-            fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
-                iter.fold(Self::zero(), Add::add)
+            fn sum<I: Iterator<Item=$VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::zero(), Add::add)
             }
         }
 
-        impl<'a, S: 'a + BaseNum> iter::Sum<&'a Self> for $VectorN<S> {
+        impl<'a, S: 'a + BaseNum> iter::Sum<&'a $VectorN<S>> for $VectorN<S> {
             #[inline]
-            fn sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
-                iter.fold(Self::zero(), Add::add)
+            fn sum<I: Iterator<Item=&'a $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::zero(), Add::add)
             }
         }
 
@@ -425,10 +282,13 @@ macro_rules! impl_vector_default {
             type Output = $VectorN<S>;
 
             #[inline]
-            default fn neg(self) -> $VectorN<S> { $VectorN::new($(-self.$field),+) }
+            fn neg(self) -> $VectorN<S> {
+                simd_fast_path!(arch::neg(&self));
+                $VectorN::new($(-self.$field),+)
+            }
         }
 
-        impl<S: BaseFloat> ApproxEq for $VectorN<S> {
+        impl<S: BaseFloat> AbsDiffEq for $VectorN<S> {
             type Epsilon = S::Epsilon;
 
             #[inline]
@@ -437,19 +297,28 @@ macro_rules! impl_vector_default {
             }
 
             #[inline]
-            fn default_max_relative() -> S::Epsilon {
-                S::default_max_relative()
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $(S::abs_diff_eq(&self.$field, &other.$field, epsilon))&&+
             }
+        }
 
+        impl<S: BaseFloat> RelativeEq for $VectorN<S> {
             #[inline]
-            fn default_max_ulps() -> u32 {
-                S::default_max_ulps()
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
             }
 
             #[inline]
             fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
                 $(S::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
             }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $VectorN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
 
             #[inline]
             fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
@@ -457,6 +326,7 @@ macro_rules! impl_vector_default {
             }
         }
 
+        #[cfg(feature = "rand")]
         impl<S: BaseFloat + Rand> Rand for $VectorN<S> {
             #[inline]
             fn rand<R: Rng>(rng: &mut R) -> $VectorN<S> {
@@ -464,36 +334,68 @@ macro_rules! impl_vector_default {
             }
         }
 
-        impl_operator_default!(<S: BaseNum> Add<$VectorN<S> > for $VectorN<S> {
-            fn add(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field + rhs.$field),+) }
-        });
+        impl<S: Bounded> Bounded for $VectorN<S> {
+            #[inline]
+            fn min_value() -> $VectorN<S> {
+                $VectorN { $($field: S::min_value()),+ }
+            }
 
-        impl_assignment_operator_default!(<S: BaseNum> AddAssign<$VectorN<S> > for $VectorN<S> {
-            fn add_assign(&mut self, other) { $(self.$field += other.$field);+ }
-        });
+            #[inline]
+            fn max_value() -> $VectorN<S> {
+                $VectorN { $($field: S::max_value()),+ }
+            }
+        }
 
-        impl_operator_default!(<S: BaseNum> Sub<$VectorN<S> > for $VectorN<S> {
-            fn sub(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field - rhs.$field),+) }
+        impl_operator!(<S: BaseNum> Add<$VectorN<S> > for $VectorN<S> {
+            fn add(lhs, rhs) -> $VectorN<S> {{
+                simd_fast_path!(arch::add(lhs.clone(), rhs.clone()));
+                $VectorN::new($(lhs.$field + rhs.$field),+)
+            }}
         });
-
-        impl_assignment_operator_default!(<S: BaseNum> SubAssign<$VectorN<S> > for $VectorN<S> {
-            fn sub_assign(&mut self, other) { $(self.$field -= other.$field);+ }
+        impl_assignment_operator!(<S: BaseNum> AddAssign<$VectorN<S> > for $VectorN<S> {
+            fn add_assign(&mut self, other) {
+                simd_fast_path!(*self; arch::add(*self, other));
+                $(self.$field += other.$field);+
+            }
         });
 
-        impl_operator_default!(<S: BaseNum> Mul<S> for $VectorN<S> {
-            fn mul(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field * scalar),+) }
+        impl_operator!(<S: BaseNum> Sub<$VectorN<S> > for $VectorN<S> {
+            fn sub(lhs, rhs) -> $VectorN<S> {{
+                simd_fast_path!(arch::sub(lhs.clone(), rhs.clone()));
+                $VectorN::new($(lhs.$field - rhs.$field),+)
+            }}
         });
-
-        impl_assignment_operator_default!(<S: BaseNum> MulAssign<S> for $VectorN<S> {
-            fn mul_assign(&mut self, scalar) { $(self.$field *= scalar);+ }
+        impl_assignment_operator!(<S: BaseNum> SubAssign<$VectorN<S> > for $VectorN<S> {
+            fn sub_assign(&mut self, other) {
+                simd_fast_path!(*self; arch::sub(*self, other));
+                $(self.$field -= other.$field);+
+            }
         });
 
-        impl_operator_default!(<S: BaseNum> Div<S> for $VectorN<S> {
-            fn div(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field / scalar),+) }
+        impl_operator!(<S: BaseNum> Mul<S> for $VectorN<S> {
+            fn mul(vector, scalar) -> $VectorN<S> {{
+                simd_fast_path!(arch::mul_scalar(vector.clone(), scalar));
+                $VectorN::new($(vector.$field * scalar),+)
+            }}
+        });
+        impl_assignment_operator!(<S: BaseNum> MulAssign<S> for $VectorN<S> {
+            fn mul_assign(&mut self, scalar) {
+                simd_fast_path!(*self; arch::mul_scalar(*self, scalar));
+                $(self.$field *= scalar);+
+            }
         });
 
-        impl_assignment_operator_default!(<S: BaseNum> DivAssign<S> for $VectorN<S> {
-            fn div_assign(&mut self, scalar) { $(self.$field /= scalar);+ }
+        impl_operator!(<S: BaseNum> Div<S> for $VectorN<S> {
+            fn div(vector, scalar) -> $VectorN<S> {{
+                simd_fast_path!(arch::div_scalar(vector.clone(), scalar));
+                $VectorN::new($(vector.$field / scalar),+)
+            }}
+        });
+        impl_assignment_operator!(<S: BaseNum> DivAssign<S> for $VectorN<S> {
+            fn div_assign(&mut self, scalar) {
+                simd_fast_path!(*self; arch::div_scalar(*self, scalar));
+                $(self.$field /= scalar);+
+            }
         });
 
         impl_operator!(<S: BaseNum> Rem<S> for $VectorN<S> {
@@ -504,44 +406,56 @@ macro_rules! impl_vector_default {
         });
 
         impl<S: BaseNum> ElementWise for $VectorN<S> {
-            #[inline] default fn add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field + rhs.$field),+) }
-            #[inline] default fn sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field - rhs.$field),+) }
-            #[inline] default fn mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field * rhs.$field),+) }
-            #[inline] default fn div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field / rhs.$field),+) }
+            #[inline] fn add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self + rhs }
+            #[inline] fn sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self - rhs }
+            #[inline] fn mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> {
+                simd_fast_path!(arch::mul(self, rhs));
+                $VectorN::new($(self.$field * rhs.$field),+)
+            }
+            #[inline] fn div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> {
+                simd_fast_path!(arch::div(self, rhs));
+                $VectorN::new($(self.$field / rhs.$field),+)
+            }
             #[inline] fn rem_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field % rhs.$field),+) }
 
-            #[inline] default fn add_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field += rhs.$field);+ }
-            #[inline] default fn sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field -= rhs.$field);+ }
-            #[inline] default fn mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field *= rhs.$field);+ }
-            #[inline] default fn div_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field /= rhs.$field);+ }
+            #[inline] fn add_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self += rhs; }
+            #[inline] fn sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self -= rhs; }
+            #[inline] fn mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.mul_element_wise(rhs); }
+            #[inline] fn div_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.div_element_wise(rhs); }
             #[inline] fn rem_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field %= rhs.$field);+ }
         }
 
         impl<S: BaseNum> ElementWise<S> for $VectorN<S> {
-            #[inline] default fn add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) }
-            #[inline] default fn sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) }
-            #[inline] default fn mul_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field * rhs),+) }
-            #[inline] default fn div_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field / rhs),+) }
+            #[inline] fn add_element_wise(self, rhs: S) -> $VectorN<S> {
+                simd_fast_path!(arch::add_scalar(self, rhs));
+                $VectorN::new($(self.$field + rhs),+)
+            }
+            #[inline] fn sub_element_wise(self, rhs: S) -> $VectorN<S> {
+                simd_fast_path!(arch::sub_scalar(self, rhs));
+                $VectorN::new($(self.$field - rhs),+)
+            }
+            #[inline] fn mul_element_wise(self, rhs: S) -> $VectorN<S> { self * rhs }
+            #[inline] fn div_element_wise(self, rhs: S) -> $VectorN<S> { self / rhs }
             #[inline] fn rem_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field % rhs),+) }
 
-            #[inline] default fn add_assign_element_wise(&mut self, rhs: S) { $(self.$field += rhs);+ }
-            #[inline] default fn sub_assign_element_wise(&mut self, rhs: S) { $(self.$field -= rhs);+ }
-            #[inline] default fn mul_assign_element_wise(&mut self, rhs: S) { $(self.$field *= rhs);+ }
-            #[inline] default fn div_assign_element_wise(&mut self, rhs: S) { $(self.$field /= rhs);+ }
+            #[inline] fn add_assign_element_wise(&mut self, rhs: S) { *self = self.add_element_wise(rhs); }
+            #[inline] fn sub_assign_element_wise(&mut self, rhs: S) { *self = self.sub_element_wise(rhs); }
+            #[inline] fn mul_assign_element_wise(&mut self, rhs: S) { *self *= rhs; }
+            #[inline] fn div_assign_element_wise(&mut self, rhs: S) { *self /= rhs; }
             #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { $(self.$field %= rhs);+ }
         }
 
         impl_scalar_ops!($VectorN<usize> { $($field),+ });
         impl_scalar_ops!($VectorN<u8> { $($field),+ });
         impl_scalar_ops!($VectorN<u16> { $($field),+ });
-        impl_scalar_ops_default!($VectorN<u32> { $($field),+ });
+        impl_scalar_ops!($VectorN<u32> { $($field),+ });
         impl_scalar_ops!($VectorN<u64> { $($field),+ });
         impl_scalar_ops!($VectorN<isize> { $($field),+ });
         impl_scalar_ops!($VectorN<i8> { $($field),+ });
         impl_scalar_ops!($VectorN<i16> { $($field),+ });
-        impl_scalar_ops_default!($VectorN<i32> { $($field),+ });
+        impl_scalar_ops!($VectorN<i32> { $($field),+ });
         impl_scalar_ops!($VectorN<i64> { $($field),+ });
-        impl_scalar_ops_default!($VectorN<f32> { $($field),+ });
+        impl_scalar_ops!($VectorN<f32> { $($field),+ });
         impl_scalar_ops!($VectorN<f64> { $($field),+ });
 
         impl_index_operators!($VectorN<S>, $n, S, usize);
@@ -552,13 +466,22 @@ macro_rules! impl_vector_default {
     }
 }
 
+// Utility macro for generating associated functions for the vectors
+// mainly duplication
+
 macro_rules! impl_scalar_ops {
     ($VectorN:ident<$S:ident> { $($field:ident),+ }) => {
         impl_operator!(Mul<$VectorN<$S>> for $S {
-            fn mul(scalar, vector) -> $VectorN<$S> { $VectorN::new($(scalar * vector.$field),+) }
+            fn mul(scalar, vector) -> $VectorN<$S> {{
+                simd_fast_path!(arch::scalar_mul(scalar, vector.clone()));
+                $VectorN::new($(scalar * vector.$field),+)
+            }}
         });
         impl_operator!(Div<$VectorN<$S>> for $S {
-            fn div(scalar, vector) -> $VectorN<$S> { $VectorN::new($(scalar / vector.$field),+) }
+            fn div(scalar, vector) -> $VectorN<$S> {{
+                simd_fast_path!(arch::scalar_div(scalar, vector.clone()));
+                $VectorN::new($(scalar / vector.$field),+)
+            }}
         });
         impl_operator!(Rem<$VectorN<$S>> for $S {
             fn rem(scalar, vector) -> $VectorN<$S> { $VectorN::new($(scalar % vector.$field),+) }
@@ -566,28 +489,10 @@ macro_rules! impl_scalar_ops {
     };
 }
 
-#[cfg(feature = "simd")]
-macro_rules! impl_scalar_ops_default {
-    ($VectorN:ident<$S:ident> { $($field:ident),+ }) => {
-        impl_operator_default!(Mul<$VectorN<$S>> for $S {
-            fn mul(scalar, vector) -> $VectorN<$S> { $VectorN::new($(scalar * vector.$field),+) }
-        });
-        impl_operator_default!(Div<$VectorN<$S>> for $S {
-            fn div(scalar, vector) -> $VectorN<$S> { $VectorN::new($(scalar / vector.$field),+) }
-        });
-        impl_operator_default!(Rem<$VectorN<$S>> for $S {
-            fn rem(scalar, vector) -> $VectorN<$S> { $VectorN::new($(scalar % vector.$field),+) }
-        });
-    };
-}
-
 impl_vector!(Vector1 { x }, 1, vec1);
 impl_vector!(Vector2 { x, y }, 2, vec2);
 impl_vector!(Vector3 { x, y, z }, 3, vec3);
-#[cfg(not(feature = "simd"))]
 impl_vector!(Vector4 { x, y, z, w }, 4, vec4);
-#[cfg(feature = "simd")]
-impl_vector_default!(Vector4 { x, y, z, w }, 4, vec4);
 
 impl_fixed_array_conversions!(Vector1<S> { x: 0 }, 1);
 impl_fixed_array_conversions!(Vector2<S> { x: 0, y: 1 }, 2);
@@ -770,6 +675,7 @@ impl<S: BaseFloat> InnerSpace for Vector3<S> {
 impl<S: BaseFloat> InnerSpace for Vector4<S> {
     #[inline]
     fn dot(self, other: Vector4<S>) -> S {
+        simd_fast_path!(arch::dot(self, other));
         Vector4::mul_element_wise(self, other).sum()
     }
 }
@@ -802,365 +708,25 @@ impl<S: fmt::Debug> fmt::Debug for Vector4<S> {
     }
 }
 
-#[cfg(feature = "simd")]
-impl From<Simdf32x4> for Vector4<f32> {
-    #[inline]
-    fn from(f: Simdf32x4) -> Self {
-        unsafe {
-            let mut ret: Self = mem::uninitialized();
-            {
-                let ret_mut: &mut [f32; 4] = ret.as_mut();
-                f.store(ret_mut.as_mut(), 0 as usize);
-            }
-            ret
-        }
-    }
-}
-
 #[cfg(feature = "simd")]
 impl Vector4<f32> {
     /// Compute and return the square root of each element.
     #[inline]
     pub fn sqrt_element_wide(self) -> Self {
-        let s: Simdf32x4 = self.into();
-        s.sqrt().into()
+        arch::sqrt(self)
     }
 
-    /// Compute and return the reciprocal of the square root of each element.
+    /// Compute and return an approximation of the reciprocal of the square
+    /// root of each element.
     #[inline]
     pub fn rsqrt_element_wide(self) -> Self {
-        let s: Simdf32x4 = self.into();
-        s.approx_rsqrt().into()
+        arch::rsqrt(self)
     }
 
-    /// Compute and return the reciprocal of each element.
+    /// Compute and return an approximation of the reciprocal of each element.
     #[inline]
     pub fn recip_element_wide(self) -> Self {
-        let s: Simdf32x4 = self.into();
-        s.approx_reciprocal().into()
-    }
-}
-
-
-
-#[cfg(feature = "simd")]
-impl Into<Simdf32x4> for Vector4<f32> {
-    #[inline]
-    fn into(self) -> Simdf32x4 {
-        let self_ref: &[f32; 4] = self.as_ref();
-        Simdf32x4::load(self_ref.as_ref(), 0 as usize)
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Add<Vector4<f32>> for Vector4<f32> {
-        fn add(lhs, rhs) -> Vector4<f32> {
-            (lhs + rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Sub<Vector4<f32>> for Vector4<f32> {
-        fn sub(lhs, rhs) -> Vector4<f32> {
-            (lhs - rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{@rs
-    [Simdf32x4]; Mul<f32> for Vector4<f32> {
-        fn mul(lhs, rhs) -> Vector4<f32> {
-            (lhs * rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{@rs
-    [Simdf32x4]; Div<f32> for Vector4<f32> {
-        fn div(lhs, rhs) -> Vector4<f32> {
-            (lhs / rhs).into()
-        }
-    }
-}
-
-
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdf32x4]; Neg for Vector4<f32> {
-        fn neg(lhs) -> Vector4<f32> {
-            (-lhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl AddAssign for Vector4<f32> {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs: Simdf32x4 = rhs.into();
-        *self = (s + rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl SubAssign for Vector4<f32> {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs: Simdf32x4 = rhs.into();
-        *self = (s - rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl MulAssign<f32> for Vector4<f32> {
-    fn mul_assign(&mut self, other: f32) {
-        let s: Simdf32x4 = (*self).into();
-        let other = Simdf32x4::splat(other);
-        *self = (s * other).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl DivAssign<f32> for Vector4<f32> {
-    fn div_assign(&mut self, other: f32) {
-        let s: Simdf32x4 = (*self).into();
-        let other = Simdf32x4::splat(other);
-        *self = (s / other).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl ElementWise for Vector4<f32> {
-    #[inline] fn add_element_wise(self, rhs: Vector4<f32>) -> Vector4<f32> { self + rhs }
-    #[inline] fn sub_element_wise(self, rhs: Vector4<f32>) -> Vector4<f32> { self - rhs }
-    #[inline] fn mul_element_wise(self, rhs: Vector4<f32>) -> Vector4<f32> {
-        let s: Simdf32x4 = self.into();
-        let rhs: Simdf32x4 = rhs.into();
-        (s * rhs).into()
-    }
-    #[inline] fn div_element_wise(self, rhs: Vector4<f32>) -> Vector4<f32> {
-        let s: Simdf32x4 = self.into();
-        let rhs: Simdf32x4 = rhs.into();
-        (s / rhs).into()
-    }
-
-    #[inline] fn add_assign_element_wise(&mut self, rhs: Vector4<f32>) { (*self) += rhs; }
-    #[inline] fn sub_assign_element_wise(&mut self, rhs: Vector4<f32>) { (*self) -= rhs; }
-    #[inline] fn mul_assign_element_wise(&mut self, rhs: Vector4<f32>) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs: Simdf32x4 = rhs.into();
-        *self = (s * rhs).into();
-    }
-    #[inline] fn div_assign_element_wise(&mut self, rhs: Vector4<f32>) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs: Simdf32x4 = rhs.into();
-        *self = (s * rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl ElementWise<f32> for Vector4<f32> {
-    #[inline] fn add_element_wise(self, rhs: f32) -> Vector4<f32> {
-        let s: Simdf32x4 = self.into();
-        let rhs = Simdf32x4::splat(rhs);
-        (s + rhs).into()
-    }
-    #[inline] fn sub_element_wise(self, rhs: f32) -> Vector4<f32> {
-        let s: Simdf32x4 = self.into();
-        let rhs = Simdf32x4::splat(rhs);
-        (s - rhs).into()
-    }
-    #[inline] fn mul_element_wise(self, rhs: f32) -> Vector4<f32> { self * rhs }
-    #[inline] fn div_element_wise(self, rhs: f32) -> Vector4<f32> { self / rhs }
-
-    #[inline] fn add_assign_element_wise(&mut self, rhs: f32) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs = Simdf32x4::splat(rhs);
-        *self = (s + rhs).into();
-    }
-    #[inline] fn sub_assign_element_wise(&mut self, rhs: f32) {
-        let s: Simdf32x4 = (*self).into();
-        let rhs = Simdf32x4::splat(rhs);
-        *self = (s - rhs).into();
-    }
-    #[inline] fn mul_assign_element_wise(&mut self, rhs: f32) { (*self) *= rhs; }
-    #[inline] fn div_assign_element_wise(&mut self, rhs: f32) { (*self) /= rhs; }
-}
-
-#[cfg(feature = "simd")]
-impl From<Simdi32x4> for Vector4<i32> {
-    #[inline]
-    fn from(f: Simdi32x4) -> Self {
-        unsafe {
-            let mut ret: Self = mem::uninitialized();
-            {
-                let ret_mut: &mut [i32; 4] = ret.as_mut();
-                f.store(ret_mut.as_mut(), 0 as usize);
-            }
-            ret
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl Into<Simdi32x4> for Vector4<i32> {
-    #[inline]
-    fn into(self) -> Simdi32x4 {
-        let self_ref: &[i32; 4] = self.as_ref();
-        Simdi32x4::load(self_ref.as_ref(), 0 as usize)
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdi32x4]; Add<Vector4<i32>> for Vector4<i32> {
-        fn add(lhs, rhs) -> Vector4<i32> {
-            (lhs + rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdi32x4]; Sub<Vector4<i32>> for Vector4<i32> {
-        fn sub(lhs, rhs) -> Vector4<i32> {
-            (lhs - rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{@rs
-    [Simdi32x4]; Mul<i32> for Vector4<i32> {
-        fn mul(lhs, rhs) -> Vector4<i32> {
-            (lhs * rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdi32x4]; Neg for Vector4<i32> {
-        fn neg(lhs) -> Vector4<i32> {
-            (-lhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl AddAssign for Vector4<i32> {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        let s: Simdi32x4 = (*self).into();
-        let rhs: Simdi32x4 = rhs.into();
-        *self = (s + rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl SubAssign for Vector4<i32> {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        let s: Simdi32x4 = (*self).into();
-        let rhs: Simdi32x4 = rhs.into();
-        *self = (s - rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl MulAssign<i32> for Vector4<i32> {
-    fn mul_assign(&mut self, other: i32) {
-        let s: Simdi32x4 = (*self).into();
-        let other = Simdi32x4::splat(other);
-        *self = (s * other).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl From<Simdu32x4> for Vector4<u32> {
-    #[inline]
-    fn from(f: Simdu32x4) -> Self {
-        unsafe {
-            let mut ret: Self = mem::uninitialized();
-            {
-                let ret_mut: &mut [u32; 4] = ret.as_mut();
-                f.store(ret_mut.as_mut(), 0 as usize);
-            }
-            ret
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl Into<Simdu32x4> for Vector4<u32> {
-    #[inline]
-    fn into(self) -> Simdu32x4 {
-        let self_ref: &[u32; 4] = self.as_ref();
-        Simdu32x4::load(self_ref.as_ref(), 0 as usize)
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdu32x4]; Add<Vector4<u32>> for Vector4<u32> {
-        fn add(lhs, rhs) -> Vector4<u32> {
-            (lhs + rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{
-    [Simdu32x4]; Sub<Vector4<u32>> for Vector4<u32> {
-        fn sub(lhs, rhs) -> Vector4<u32> {
-            (lhs - rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl_operator_simd!{@rs
-    [Simdu32x4]; Mul<u32> for Vector4<u32> {
-        fn mul(lhs, rhs) -> Vector4<u32> {
-            (lhs * rhs).into()
-        }
-    }
-}
-
-#[cfg(feature = "simd")]
-impl AddAssign for Vector4<u32> {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        let s: Simdu32x4 = (*self).into();
-        let rhs: Simdu32x4 = rhs.into();
-        *self = (s + rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl SubAssign for Vector4<u32> {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        let s: Simdu32x4 = (*self).into();
-        let rhs: Simdu32x4 = rhs.into();
-        *self = (s - rhs).into();
-    }
-}
-
-#[cfg(feature = "simd")]
-impl MulAssign<u32> for Vector4<u32> {
-    fn mul_assign(&mut self, other: u32) {
-        let s: Simdu32x4 = (*self).into();
-        let other = Simdu32x4::splat(other);
-        *self = (s * other).into();
+        arch::recip(self)
     }
 }
 
@@ -0,0 +1,593 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wide vectors, quaternions and matrices, which store several values in
+//! structure-of-arrays form.
+//!
+//! Each component is an array with one lane per value, so every operation
+//! applies the same arithmetic to all of the lanes at once. This layout lets
+//! the compiler vectorize batch math, such as transforming the points of a
+//! particle system, far more effectively than a slice of `Vector3`s.
+//!
+//! Each lane gives the same result as the corresponding operation on a
+//! single `Vector3`, `Quaternion` or `Matrix4`.
+
+use core::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use vector::{Vector3, Vector4};
+
+/// Four three-dimensional vectors, stored as an array of lanes for each
+/// component.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vector3x4<S> {
+    /// The x components of the lanes.
+    pub x: [S; 4],
+    /// The y components of the lanes.
+    pub y: [S; 4],
+    /// The z components of the lanes.
+    pub z: [S; 4],
+}
+
+/// Eight three-dimensional vectors, stored as an array of lanes for each
+/// component.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vector3x8<S> {
+    /// The x components of the lanes.
+    pub x: [S; 8],
+    /// The y components of the lanes.
+    pub y: [S; 8],
+    /// The z components of the lanes.
+    pub z: [S; 8],
+}
+
+/// Four quaternions, stored as an array of lanes for each component.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quaternionx4<S> {
+    /// The scalar parts of the lanes.
+    pub s: [S; 4],
+    /// The vector parts of the lanes.
+    pub v: Vector3x4<S>,
+}
+
+/// Eight quaternions, stored as an array of lanes for each component.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quaternionx8<S> {
+    /// The scalar parts of the lanes.
+    pub s: [S; 8],
+    /// The vector parts of the lanes.
+    pub v: Vector3x8<S>,
+}
+
+/// Four 4x4 matrices, such as the bone transforms used for skinning, stored
+/// as an array of lanes for each element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4Lanes4<S> {
+    /// The first column of the lanes, with an array of lanes for each row.
+    pub x: [[S; 4]; 4],
+    /// The second column of the lanes, with an array of lanes for each row.
+    pub y: [[S; 4]; 4],
+    /// The third column of the lanes, with an array of lanes for each row.
+    pub z: [[S; 4]; 4],
+    /// The fourth column of the lanes, with an array of lanes for each row.
+    pub w: [[S; 4]; 4],
+}
+
+/// Eight 4x4 matrices, stored as an array of lanes for each element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4Lanes8<S> {
+    /// The first column of the lanes, with an array of lanes for each row.
+    pub x: [[S; 8]; 4],
+    /// The second column of the lanes, with an array of lanes for each row.
+    pub y: [[S; 8]; 4],
+    /// The third column of the lanes, with an array of lanes for each row.
+    pub z: [[S; 8]; 4],
+    /// The fourth column of the lanes, with an array of lanes for each row.
+    pub w: [[S; 8]; 4],
+}
+
+/// Build an array of lanes by evaluating an expression for each lane index.
+macro_rules! lanes {
+    ($n:expr, |$i:ident| $e:expr) => {{
+        let mut lanes = [S::zero(); $n];
+        for ($i, lane) in lanes.iter_mut().enumerate() {
+            *lane = $e;
+        }
+        lanes
+    }}
+}
+
+macro_rules! impl_wide {
+    ($VectorN:ident, $QuaternionN:ident, $MatrixN:ident, $n:expr) => {
+        impl<S: BaseFloat> $VectorN<S> {
+            /// Construct a wide vector from the lanes of each component.
+            #[inline]
+            pub fn new(x: [S; $n], y: [S; $n], z: [S; $n]) -> $VectorN<S> {
+                $VectorN { x, y, z }
+            }
+
+            /// A wide vector with every lane set to `v`.
+            #[inline]
+            pub fn splat(v: Vector3<S>) -> $VectorN<S> {
+                $VectorN::new([v.x; $n], [v.y; $n], [v.z; $n])
+            }
+
+            /// A wide vector with every lane set to zero.
+            #[inline]
+            pub fn zero() -> $VectorN<S> {
+                $VectorN::splat(Vector3::zero())
+            }
+
+            /// The vector in lane `i`.
+            #[inline]
+            pub fn lane(&self, i: usize) -> Vector3<S> {
+                Vector3::new(self.x[i], self.y[i], self.z[i])
+            }
+
+            /// Set lane `i` to `v`.
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, v: Vector3<S>) {
+                self.x[i] = v.x;
+                self.y[i] = v.y;
+                self.z[i] = v.z;
+            }
+
+            /// Split this wide vector into its lanes.
+            #[inline]
+            pub fn to_vectors(&self) -> [Vector3<S>; $n] {
+                let mut vectors = [Vector3::zero(); $n];
+                for (i, v) in vectors.iter_mut().enumerate() {
+                    *v = self.lane(i);
+                }
+                vectors
+            }
+
+            /// Split this wide vector into its lanes, treating each as a point.
+            #[inline]
+            pub fn to_points(&self) -> [Point3<S>; $n] {
+                let mut points = [Point3::origin(); $n];
+                for (i, p) in points.iter_mut().enumerate() {
+                    *p = Point3::from_vec(self.lane(i));
+                }
+                points
+            }
+
+            /// The dot product of each pair of lanes.
+            #[inline]
+            pub fn dot(self, other: $VectorN<S>) -> [S; $n] {
+                lanes!($n, |i| self.x[i] * other.x[i] + self.y[i] * other.y[i] + self.z[i] * other.z[i])
+            }
+
+            /// The cross product of each pair of lanes.
+            #[inline]
+            pub fn cross(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| (self.y[i] * other.z[i]) - (self.z[i] * other.y[i])),
+                              lanes!($n, |i| (self.z[i] * other.x[i]) - (self.x[i] * other.z[i])),
+                              lanes!($n, |i| (self.x[i] * other.y[i]) - (self.y[i] * other.x[i])))
+            }
+
+            /// The squared magnitude of each lane.
+            #[inline]
+            pub fn magnitude2(self) -> [S; $n] {
+                self.dot(self)
+            }
+
+            /// The magnitude of each lane.
+            #[inline]
+            pub fn magnitude(self) -> [S; $n] {
+                let magnitude2 = self.magnitude2();
+                lanes!($n, |i| magnitude2[i].sqrt())
+            }
+
+            /// Multiply each lane by the matching scalar in `factors`.
+            #[inline]
+            pub fn mul_lanes(self, factors: [S; $n]) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| self.x[i] * factors[i]),
+                              lanes!($n, |i| self.y[i] * factors[i]),
+                              lanes!($n, |i| self.z[i] * factors[i]))
+            }
+
+            /// Scale each lane to unit length.
+            #[inline]
+            pub fn normalize(self) -> $VectorN<S> {
+                let magnitude = self.magnitude();
+                self.mul_lanes(lanes!($n, |i| S::one() / magnitude[i]))
+            }
+
+            /// Transform each lane as a point by `m`, including the
+            /// perspective divide, like `Matrix4::transform_point`.
+            #[inline]
+            pub fn transform_point(self, m: &Matrix4<S>) -> $VectorN<S> {
+                let row = |r: usize| lanes!($n, |i| m.x[r] * self.x[i] + m.y[r] * self.y[i] + m.z[r] * self.z[i] + m.w[r]);
+                let w = row(3);
+                $VectorN::new(row(0), row(1), row(2)).mul_lanes(lanes!($n, |i| S::one() / w[i]))
+            }
+
+            /// Transform each lane as a direction by `m`, ignoring its
+            /// translation, like `Matrix4::transform_vector`.
+            #[inline]
+            pub fn transform_vector(self, m: &Matrix4<S>) -> $VectorN<S> {
+                let row = |r: usize| lanes!($n, |i| m.x[r] * self.x[i] + m.y[r] * self.y[i] + m.z[r] * self.z[i]);
+                $VectorN::new(row(0), row(1), row(2))
+            }
+        }
+
+        impl<S: BaseFloat> From<[Vector3<S>; $n]> for $VectorN<S> {
+            #[inline]
+            fn from(vectors: [Vector3<S>; $n]) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| vectors[i].x),
+                              lanes!($n, |i| vectors[i].y),
+                              lanes!($n, |i| vectors[i].z))
+            }
+        }
+
+        impl<S: BaseFloat> From<[Point3<S>; $n]> for $VectorN<S> {
+            #[inline]
+            fn from(points: [Point3<S>; $n]) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| points[i].x),
+                              lanes!($n, |i| points[i].y),
+                              lanes!($n, |i| points[i].z))
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Neg for $VectorN<S> {
+            fn neg(v) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| -v.x[i]), lanes!($n, |i| -v.y[i]), lanes!($n, |i| -v.z[i]))
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Add<$VectorN<S> > for $VectorN<S> {
+            fn add(lhs, rhs) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| lhs.x[i] + rhs.x[i]),
+                              lanes!($n, |i| lhs.y[i] + rhs.y[i]),
+                              lanes!($n, |i| lhs.z[i] + rhs.z[i]))
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Sub<$VectorN<S> > for $VectorN<S> {
+            fn sub(lhs, rhs) -> $VectorN<S> {
+                $VectorN::new(lanes!($n, |i| lhs.x[i] - rhs.x[i]),
+                              lanes!($n, |i| lhs.y[i] - rhs.y[i]),
+                              lanes!($n, |i| lhs.z[i] - rhs.z[i]))
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Mul<S> for $VectorN<S> {
+            fn mul(lhs, rhs) -> $VectorN<S> {
+                lhs.mul_lanes([rhs; $n])
+            }
+        });
+
+        impl<S: BaseFloat> AbsDiffEq for $VectorN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                (0..$n).all(|i| Vector3::abs_diff_eq(&self.lane(i), &other.lane(i), epsilon))
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $VectorN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                (0..$n).all(|i| Vector3::relative_eq(&self.lane(i), &other.lane(i), epsilon, max_relative))
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $VectorN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                (0..$n).all(|i| Vector3::ulps_eq(&self.lane(i), &other.lane(i), epsilon, max_ulps))
+            }
+        }
+
+        impl<S: BaseFloat> $QuaternionN<S> {
+            /// Construct a wide quaternion from the lanes of its scalar and
+            /// vector parts.
+            #[inline]
+            pub fn from_sv(s: [S; $n], v: $VectorN<S>) -> $QuaternionN<S> {
+                $QuaternionN { s, v }
+            }
+
+            /// A wide quaternion with every lane set to `q`.
+            #[inline]
+            pub fn splat(q: Quaternion<S>) -> $QuaternionN<S> {
+                $QuaternionN::from_sv([q.s; $n], $VectorN::splat(q.v))
+            }
+
+            /// The quaternion in lane `i`.
+            #[inline]
+            pub fn lane(&self, i: usize) -> Quaternion<S> {
+                Quaternion::from_sv(self.s[i], self.v.lane(i))
+            }
+
+            /// Set lane `i` to `q`.
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, q: Quaternion<S>) {
+                self.s[i] = q.s;
+                self.v.set_lane(i, q.v);
+            }
+
+            /// Split this wide quaternion into its lanes.
+            #[inline]
+            pub fn to_quaternions(&self) -> [Quaternion<S>; $n] {
+                let mut quaternions = [Quaternion::one(); $n];
+                for (i, q) in quaternions.iter_mut().enumerate() {
+                    *q = self.lane(i);
+                }
+                quaternions
+            }
+
+            /// The conjugate of each lane.
+            #[inline]
+            pub fn conjugate(self) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(self.s, -self.v)
+            }
+
+            /// The dot product of each pair of lanes.
+            #[inline]
+            pub fn dot(self, other: $QuaternionN<S>) -> [S; $n] {
+                let v = self.v.dot(other.v);
+                lanes!($n, |i| self.s[i] * other.s[i] + v[i])
+            }
+
+            /// The squared magnitude of each lane.
+            #[inline]
+            pub fn magnitude2(self) -> [S; $n] {
+                self.dot(self)
+            }
+
+            /// The magnitude of each lane.
+            #[inline]
+            pub fn magnitude(self) -> [S; $n] {
+                let magnitude2 = self.magnitude2();
+                lanes!($n, |i| magnitude2[i].sqrt())
+            }
+
+            /// Scale each lane to unit length.
+            #[inline]
+            pub fn normalize(self) -> $QuaternionN<S> {
+                let magnitude = self.magnitude();
+                let factors = lanes!($n, |i| S::one() / magnitude[i]);
+                $QuaternionN::from_sv(lanes!($n, |i| self.s[i] * factors[i]), self.v.mul_lanes(factors))
+            }
+
+            /// Rotate each lane of `v` by the matching lane of this
+            /// quaternion, which must be normalized.
+            #[inline]
+            pub fn rotate_vector(self, v: $VectorN<S>) -> $VectorN<S> {
+                self * v
+            }
+        }
+
+        impl<S: BaseFloat> From<[Quaternion<S>; $n]> for $QuaternionN<S> {
+            #[inline]
+            fn from(quaternions: [Quaternion<S>; $n]) -> $QuaternionN<S> {
+                let v = $VectorN::new(lanes!($n, |i| quaternions[i].v.x),
+                                      lanes!($n, |i| quaternions[i].v.y),
+                                      lanes!($n, |i| quaternions[i].v.z));
+                $QuaternionN::from_sv(lanes!($n, |i| quaternions[i].s), v)
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Mul<$QuaternionN<S> > for $QuaternionN<S> {
+            fn mul(lhs, rhs) -> $QuaternionN<S> {{
+                let (a, b) = (lhs.v, rhs.v);
+                $QuaternionN::from_sv(
+                    lanes!($n, |i| lhs.s[i] * rhs.s[i] - a.x[i] * b.x[i] - a.y[i] * b.y[i] - a.z[i] * b.z[i]),
+                    $VectorN::new(lanes!($n, |i| lhs.s[i] * b.x[i] + a.x[i] * rhs.s[i] + a.y[i] * b.z[i] - a.z[i] * b.y[i]),
+                                  lanes!($n, |i| lhs.s[i] * b.y[i] + a.y[i] * rhs.s[i] + a.z[i] * b.x[i] - a.x[i] * b.z[i]),
+                                  lanes!($n, |i| lhs.s[i] * b.z[i] + a.z[i] * rhs.s[i] + a.x[i] * b.y[i] - a.y[i] * b.x[i])))
+            }}
+        });
+
+        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $QuaternionN<S> {
+            fn mul(lhs, rhs) -> $VectorN<S> {{
+                let rhs = rhs.clone();
+                let two: S = cast(2i8).unwrap();
+                let tmp = lhs.v.cross(rhs) + rhs.mul_lanes(lhs.s);
+                (lhs.v.cross(tmp) * two) + rhs
+            }}
+        });
+
+        impl<S: BaseFloat> AbsDiffEq for $QuaternionN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                (0..$n).all(|i| Quaternion::abs_diff_eq(&self.lane(i), &other.lane(i), epsilon))
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $QuaternionN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                (0..$n).all(|i| Quaternion::relative_eq(&self.lane(i), &other.lane(i), epsilon, max_relative))
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $QuaternionN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                (0..$n).all(|i| Quaternion::ulps_eq(&self.lane(i), &other.lane(i), epsilon, max_ulps))
+            }
+        }
+
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// A wide matrix with every lane set to `m`.
+            #[inline]
+            pub fn splat(m: Matrix4<S>) -> $MatrixN<S> {
+                $MatrixN::from([m; $n])
+            }
+
+            /// The matrix in lane `i`.
+            #[inline]
+            pub fn lane(&self, i: usize) -> Matrix4<S> {
+                let column = |c: &[[S; $n]; 4]| Vector4::new(c[0][i], c[1][i], c[2][i], c[3][i]);
+                Matrix4::from_cols(column(&self.x), column(&self.y), column(&self.z), column(&self.w))
+            }
+
+            /// Set lane `i` to `m`.
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, m: Matrix4<S>) {
+                for r in 0..4 {
+                    self.x[r][i] = m.x[r];
+                    self.y[r][i] = m.y[r];
+                    self.z[r][i] = m.z[r];
+                    self.w[r][i] = m.w[r];
+                }
+            }
+
+            /// Split this wide matrix into its lanes.
+            #[inline]
+            pub fn to_matrices(&self) -> [Matrix4<S>; $n] {
+                let mut matrices = [Matrix4::one(); $n];
+                for (i, m) in matrices.iter_mut().enumerate() {
+                    *m = self.lane(i);
+                }
+                matrices
+            }
+
+            /// Transform each lane of `point` as a point by the matching lane
+            /// of this matrix, including the perspective divide, like
+            /// `Matrix4::transform_point`.
+            #[inline]
+            pub fn transform_point(&self, point: $VectorN<S>) -> $VectorN<S> {
+                let row = |r: usize| lanes!($n, |i| self.x[r][i] * point.x[i] + self.y[r][i] * point.y[i] +
+                                                    self.z[r][i] * point.z[i] + self.w[r][i]);
+                let w = row(3);
+                $VectorN::new(row(0), row(1), row(2)).mul_lanes(lanes!($n, |i| S::one() / w[i]))
+            }
+
+            /// Transform each lane of `vector` as a direction by the matching
+            /// lane of this matrix, ignoring its translation, like
+            /// `Matrix4::transform_vector`.
+            #[inline]
+            pub fn transform_vector(&self, vector: $VectorN<S>) -> $VectorN<S> {
+                let row = |r: usize| lanes!($n, |i| self.x[r][i] * vector.x[i] + self.y[r][i] * vector.y[i] +
+                                                    self.z[r][i] * vector.z[i]);
+                $VectorN::new(row(0), row(1), row(2))
+            }
+        }
+
+        impl<S: BaseFloat> From<[Matrix4<S>; $n]> for $MatrixN<S> {
+            #[inline]
+            fn from(matrices: [Matrix4<S>; $n]) -> $MatrixN<S> {
+                let zero = [[S::zero(); $n]; 4];
+                let mut wide = $MatrixN { x: zero, y: zero, z: zero, w: zero };
+                for (i, &m) in matrices.iter().enumerate() {
+                    wide.set_lane(i, m);
+                }
+                wide
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Mul<$MatrixN<S> > for $MatrixN<S> {
+            fn mul(lhs, rhs) -> $MatrixN<S> {{
+                let column = |c: &[[S; $n]; 4]| {
+                    let row = |r: usize| lanes!($n, |i| lhs.x[r][i] * c[0][i] + lhs.y[r][i] * c[1][i] +
+                                                        lhs.z[r][i] * c[2][i] + lhs.w[r][i] * c[3][i]);
+                    [row(0), row(1), row(2), row(3)]
+                };
+                $MatrixN { x: column(&rhs.x), y: column(&rhs.y), z: column(&rhs.z), w: column(&rhs.w) }
+            }}
+        });
+
+        impl<S: BaseFloat> AbsDiffEq for $MatrixN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                (0..$n).all(|i| Matrix4::abs_diff_eq(&self.lane(i), &other.lane(i), epsilon))
+            }
+        }
+
+        impl<S: BaseFloat> RelativeEq for $MatrixN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                (0..$n).all(|i| Matrix4::relative_eq(&self.lane(i), &other.lane(i), epsilon, max_relative))
+            }
+        }
+
+        impl<S: BaseFloat> UlpsEq for $MatrixN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                (0..$n).all(|i| Matrix4::ulps_eq(&self.lane(i), &other.lane(i), epsilon, max_ulps))
+            }
+        }
+    }
+}
+
+impl_wide!(Vector3x4, Quaternionx4, Matrix4Lanes4, 4);
+impl_wide!(Vector3x8, Quaternionx8, Matrix4Lanes8, 8);
@@ -0,0 +1,116 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_new_orders_corners() {
+    let aabb = Aabb3::new(Point3::new(1, -2, 3), Point3::new(-1, 2, -3));
+    assert_eq!(aabb.min, Point3::new(-1, -2, -3));
+    assert_eq!(aabb.max, Point3::new(1, 2, 3));
+}
+
+#[test]
+fn test_from_points() {
+    let points = [
+        Point2::new(1.0f64, 1.0),
+        Point2::new(2.0, 3.0),
+        Point2::new(3.0, -1.0),
+    ];
+    let aabb = Aabb2::from_points(&points).unwrap();
+    assert_eq!(aabb, Aabb2::new(Point2::new(1.0, -1.0), Point2::new(3.0, 3.0)));
+    assert_eq!(Aabb2::from_point_iter(points.iter().cloned()), Some(aabb));
+    assert_eq!(Aabb2::<f64>::from_points(&[]), None);
+}
+
+#[test]
+fn test_grow_and_union() {
+    let a = Aabb3::from_point(Point3::new(0, 0, 0)).grow(Point3::new(1, 2, -1));
+    assert_eq!(a, Aabb3::new(Point3::new(0, 0, -1), Point3::new(1, 2, 0)));
+
+    let b = Aabb3::new(Point3::new(3, 3, 3), Point3::new(4, 4, 4));
+    assert_eq!(a.union(b), Aabb3::new(Point3::new(0, 0, -1), Point3::new(4, 4, 4)));
+}
+
+#[test]
+fn test_intersection() {
+    let a = Aabb2::new(Point2::new(0, 0), Point2::new(2, 2));
+    let b = Aabb2::new(Point2::new(1, 1), Point2::new(3, 3));
+    let c = Aabb2::new(Point2::new(5, 5), Point2::new(6, 6));
+
+    assert!(a.intersects(b));
+    assert_eq!(a.intersection(b), Some(Aabb2::new(Point2::new(1, 1), Point2::new(2, 2))));
+    assert!(!a.intersects(c));
+    assert_eq!(a.intersection(c), None);
+}
+
+#[test]
+fn test_contains() {
+    let a = Aabb3::new(Point3::new(0, 0, 0), Point3::new(4, 4, 4));
+    assert!(a.contains_point(Point3::new(2, 2, 2)));
+    assert!(a.contains_point(Point3::new(4, 0, 4)));
+    assert!(!a.contains_point(Point3::new(5, 2, 2)));
+    assert!(a.contains(Aabb3::new(Point3::new(1, 1, 1), Point3::new(3, 3, 3))));
+    assert!(!a.contains(Aabb3::new(Point3::new(1, 1, 1), Point3::new(5, 3, 3))));
+}
+
+#[test]
+fn test_measures() {
+    let a = Aabb3::new(Point3::new(-1.0f64, 0.0, 1.0), Point3::new(1.0, 4.0, 4.0));
+    assert_eq!(a.center(), Point3::new(0.0, 2.0, 2.5));
+    assert_eq!(a.extent(), Vector3::new(2.0, 4.0, 3.0));
+    assert_eq!(a.volume(), 24.0);
+
+    let b = Aabb2::new(Point2::new(0, 0), Point2::new(2, 3));
+    assert_eq!(b.area(), 6);
+}
+
+#[test]
+fn test_transform_matrix4() {
+    let a = Aabb3::new(Point3::new(-1.0f64, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_scale(2.0);
+    assert_ulps_eq!(a.transform(&m), Aabb3::new(Point3::new(-1.0, 0.0, 1.0), Point3::new(3.0, 4.0, 5.0)));
+}
+
+#[test]
+fn test_transform_decomposed() {
+    let a = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let t = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_angle_z(Deg(45.0)),
+        disp: Vector3::new(0.0, 0.0, 1.0),
+    };
+    let half_sqrt2 = 0.5f64.sqrt();
+    let expected = Aabb3::new(Point3::new(-half_sqrt2, 0.0, 1.0),
+                              Point3::new(half_sqrt2, 2.0 * half_sqrt2, 2.0));
+    assert_ulps_eq!(a.transform(&t), expected);
+
+    let bounds = a.transform(&t);
+    for &corner in a.corners().iter() {
+        assert!(bounds.contains_point(t.transform_point(corner)));
+    }
+}
+
+#[test]
+fn test_transform_2d() {
+    let a = Aabb2::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 1.0));
+    let m = Matrix3::from(Matrix2::from_angle(Deg(90.0)));
+    assert_relative_eq!(a.transform(&m), Aabb2::new(Point2::new(-1.0, 0.0), Point2::new(0.0, 2.0)),
+                        epsilon = 1.0e-12);
+}
@@ -0,0 +1,94 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+const EPSILON: f64 = 1.0e-12;
+
+fn affine3() -> Affine3<f64> {
+    // Includes a shear, which none of the other transforms can represent
+    Affine3::new(Matrix3::new(2.0, 0.5, 0.0,
+                              0.0, 1.0, -1.0,
+                              1.0, 0.0, 3.0),
+                 Vector3::new(6.0, -7.0, 8.0))
+}
+
+#[test]
+fn test_matrix4_round_trip() {
+    let a = affine3();
+    let m = Matrix4::from(a);
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(Affine3::from_matrix4(m), Some(a));
+    assert_relative_eq!(a.transform_point(p), m.transform_point(p), epsilon = EPSILON);
+    assert_relative_eq!(a.transform_vector(p.to_vec()), m.transform_vector(p.to_vec()), epsilon = EPSILON);
+    assert_eq!(Affine3::from_matrix4(perspective(Deg(60.0), 1.0, 0.1, 100.0)), None);
+}
+
+#[test]
+fn test_inverse_and_concat() {
+    let a = affine3();
+    let m = Matrix4::from(a);
+    let inv = a.inverse_transform().unwrap();
+    assert_relative_eq!(Matrix4::from(inv), m.invert().unwrap(), epsilon = EPSILON);
+    assert_relative_eq!(a.concat(&inv), Affine3::one(), epsilon = EPSILON);
+    assert_relative_eq!(Matrix4::from(a.concat(&inv.concat(&a))), m, epsilon = EPSILON);
+
+    let singular = Affine3::from_linear(Matrix3::from_diagonal(Vector3::new(1.0, 0.0, 2.0)));
+    assert!(singular.inverse_transform().is_none());
+}
+
+#[test]
+fn test_normal_matrix() {
+    let a = affine3();
+    let n = a.normal_matrix().unwrap();
+    // A tangent of the plane with normal `normal` stays perpendicular to the transformed normal
+    let normal = Vector3::new(1.0, 1.0, 0.0);
+    let tangent = Vector3::new(1.0, -1.0, 4.0);
+    assert_relative_eq!(normal.dot(tangent), 0.0);
+    assert_relative_eq!((n * normal).dot(a.transform_vector(tangent)), 0.0, epsilon = EPSILON);
+
+    let rotation = Affine3::from(Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_angle_y(Deg(30.0)),
+        disp: Vector3::zero(),
+    });
+    assert_relative_eq!(rotation.normal_matrix().unwrap(), rotation.linear, epsilon = EPSILON);
+}
+
+#[test]
+fn test_look_at() {
+    let eye = Point3::new(1.0f64, 2.0, -5.0);
+    let center = Point3::new(0.0f64, 0.5, 0.0);
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let a: Affine3<f64> = Transform::look_at(eye, center, up);
+    assert_relative_eq!(Matrix4::from(a), Matrix4::look_at(eye, center, up), epsilon = EPSILON);
+}
+
+#[test]
+fn test_affine2() {
+    let a = Affine2::new(Matrix2::new(1.0, 2.0, 0.5, -1.0), Vector2::new(3.0, 4.0));
+    let m = Matrix3::from(a);
+    let p = Point2::new(-1.0, 2.0);
+    assert_eq!(Affine2::from_matrix3(m), Some(a));
+    assert_relative_eq!(a.transform_point(p), m.transform_point(p), epsilon = EPSILON);
+    assert_relative_eq!(Matrix3::from(a.inverse_transform().unwrap()), m.invert().unwrap(), epsilon = EPSILON);
+
+    let dec = Decomposed { scale: 2.0, rot: Basis2::from_angle(Deg(45.0)), disp: Vector2::new(1.0, 1.0) };
+    assert_relative_eq!(Matrix3::from(Affine2::from(dec)), Matrix3::from(dec), epsilon = EPSILON);
+}
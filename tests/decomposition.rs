@@ -0,0 +1,371 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+const EPSILON: f64 = 1.0e-10;
+
+fn a3() -> Matrix3<f64> {
+    Matrix3::new(0.0, 2.0, 1.0,
+                 4.0, -1.0, 3.0,
+                 -2.0, 5.0, 2.0)
+}
+
+fn a4() -> Matrix4<f64> {
+    Matrix4::new(2.0, -1.0, 0.0, 3.0,
+                 1.0, 4.0, -2.0, 0.5,
+                 0.0, 3.0, 5.0, -1.0,
+                 -3.0, 2.0, 1.0, 6.0)
+}
+
+fn spd3() -> Matrix3<f64> {
+    Matrix3::new(4.0, 2.0, -2.0,
+                 2.0, 5.0, 1.0,
+                 -2.0, 1.0, 6.0)
+}
+
+fn is_upper_triangular(m: Matrix4<f64>) -> bool {
+    (0..4).all(|c| ((c + 1)..4).all(|r| m[c][r] == 0.0))
+}
+
+mod lu {
+    use super::*;
+
+    #[test]
+    fn test_factors() {
+        let lu = a4().lu();
+        assert_relative_eq!(lu.p * a4(), lu.l * lu.u, epsilon = EPSILON);
+        assert!(is_upper_triangular(lu.u));
+        assert!(is_upper_triangular(lu.l.transpose()));
+        assert_eq!(lu.l.diagonal(), Vector4::new(1.0, 1.0, 1.0, 1.0));
+
+        // The first column of `a3` needs a pivot
+        let lu = a3().lu();
+        assert_relative_eq!(lu.p * a3(), lu.l * lu.u, epsilon = EPSILON);
+        assert!(lu.l.x.y.abs() <= 1.0 && lu.l.x.z.abs() <= 1.0);
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = Vector4::new(1.0, -2.0, 0.5, 3.0);
+        let b = a4() * x;
+        assert_relative_eq!(a4().lu().solve(b).unwrap(), x, epsilon = EPSILON);
+        assert_relative_eq!(a3().lu().solve(a3() * x.truncate()).unwrap(), x.truncate(), epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_relative_eq!(a4().lu().determinant(), a4().determinant(), epsilon = EPSILON);
+        assert_relative_eq!(a3().lu().determinant(), a3().determinant(), epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_singular() {
+        let m = Matrix3::new(1.0, 2.0, 3.0,
+                             2.0, 4.0, 6.0,
+                             0.0, 1.0, 1.0);
+        let lu = m.lu();
+        assert_relative_eq!(lu.p * m, lu.l * lu.u, epsilon = EPSILON);
+        assert_eq!(lu.solve(Vector3::new(1.0, 0.0, 0.0)), None);
+        assert_eq!(lu.determinant(), 0.0);
+
+        // Singular, but only up to rounding
+        let m = Matrix3::new(1.0, 2.0, 3.0,
+                             4.0, 5.0, 6.0,
+                             7.0, 8.0, 9.0);
+        assert_eq!(m.lu().solve(Vector3::new(1.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_scale() {
+        for &scale in [1.0e-8f32, 1.0e8].iter() {
+            let m = Matrix3::from_diagonal(Vector3::new(scale, scale, scale));
+            let x = m.lu().solve(Vector3::new(1.0, 2.0, 3.0)).unwrap();
+            assert_relative_eq!(x, Vector3::new(1.0, 2.0, 3.0) / scale);
+            assert_relative_eq!(x, m.invert().unwrap() * Vector3::new(1.0, 2.0, 3.0));
+        }
+
+        let m = Matrix4::from_diagonal(Vector4::new(1.0e-9f32, 1.0e-9, 1.0e-9, 0.0));
+        assert_eq!(m.lu().solve(Vector4::unit_x()), None);
+    }
+}
+
+mod qr {
+    use super::*;
+
+    #[test]
+    fn test_factors() {
+        let qr = a4().qr();
+        assert_relative_eq!(qr.q * qr.r, a4(), epsilon = EPSILON);
+        assert_relative_eq!(qr.q.transpose() * qr.q, Matrix4::identity(), epsilon = EPSILON);
+        assert!(is_upper_triangular(qr.r));
+
+        let m = Matrix2::new(3.0f64, 4.0, 1.0, 2.0);
+        let qr = m.qr();
+        assert_relative_eq!(qr.q * qr.r, m, epsilon = EPSILON);
+        assert_relative_eq!(qr.r.x.x.abs(), 5.0, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = Vector4::new(1.0, -2.0, 0.5, 3.0);
+        assert_relative_eq!(a4().qr().solve(a4() * x).unwrap(), x, epsilon = EPSILON);
+        assert_eq!(Matrix2::new(1.0, 2.0, 2.0, 4.0).qr().solve(Vector2::new(1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_scale() {
+        for &scale in [1.0e-8f32, 1.0e8].iter() {
+            let m = Matrix3::from_diagonal(Vector3::new(scale, scale, scale));
+            let x = m.qr().solve(Vector3::new(1.0, 2.0, 3.0)).unwrap();
+            assert_relative_eq!(x, Vector3::new(1.0, 2.0, 3.0) / scale);
+        }
+
+        let m = Matrix2::new(1.0e-9f32, 2.0e-9, 2.0e-9, 4.0e-9);
+        assert_eq!(m.qr().solve(Vector2::new(1.0, 1.0)), None);
+    }
+}
+
+mod cholesky {
+    use super::*;
+
+    #[test]
+    fn test_factors() {
+        let l = spd3().cholesky().unwrap().l;
+        assert_relative_eq!(l * l.transpose(), spd3(), epsilon = EPSILON);
+        assert_eq!(l.y.x, 0.0);
+        assert_eq!(l.z.x, 0.0);
+        assert_eq!(l.z.y, 0.0);
+        assert!(l.diagonal().x > 0.0 && l.diagonal().y > 0.0 && l.diagonal().z > 0.0);
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = Vector3::new(1.0, -2.0, 0.5);
+        assert_relative_eq!(spd3().cholesky().unwrap().solve(spd3() * x), x, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_scale() {
+        for &scale in [1.0e-15f32, 1.0e-8, 1.0e8].iter() {
+            let m = Matrix3::from_diagonal(Vector3::new(scale, scale, scale));
+            let x = m.cholesky().unwrap().solve(Vector3::new(1.0, 2.0, 3.0));
+            assert_relative_eq!(x, Vector3::new(1.0, 2.0, 3.0) / scale);
+        }
+
+        // A badly conditioned, but positive-definite, matrix
+        let m = Matrix2::from_diagonal(Vector2::new(1.0f64, 1.0e-40));
+        let x = m.cholesky().unwrap().solve(Vector2::new(1.0, 1.0));
+        assert_relative_eq!(x, Vector2::new(1.0, 1.0e40));
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        assert_eq!(Matrix2::new(1.0, 2.0, 2.0, 1.0).cholesky(), None);
+        assert_eq!(Matrix3::from_value(-1.0).cholesky(), None);
+        assert_eq!(Matrix4::<f64>::zero().cholesky(), None);
+    }
+}
+
+mod svd {
+    use super::*;
+
+    #[test]
+    fn test_factors() {
+        let svd = a4().svd();
+        let s = svd.singular_values;
+        assert_relative_eq!(svd.u * Matrix4::from_diagonal(s) * svd.v.transpose(), a4(), epsilon = EPSILON);
+        assert_relative_eq!(svd.u.transpose() * svd.u, Matrix4::identity(), epsilon = EPSILON);
+        assert_relative_eq!(svd.v.transpose() * svd.v, Matrix4::identity(), epsilon = EPSILON);
+        assert!(s.x >= s.y && s.y >= s.z && s.z >= s.w && s.w >= 0.0);
+        assert_relative_eq!(s.product(), a4().determinant().abs(), epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_rotation_and_scale() {
+        let m = Matrix2::from_angle(Deg(30.0)) * Matrix2::from_cols(Vector2::new(2.0, 0.0), Vector2::new(0.0, -5.0));
+        let svd = m.svd();
+        assert_relative_eq!(svd.singular_values, Vector2::new(5.0, 2.0), epsilon = EPSILON);
+        assert_relative_eq!(svd.u * Matrix2::from_diagonal(svd.singular_values) * svd.v.transpose(), m,
+                            epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        let m = Matrix3::from_cols(Vector3::new(1.0, 2.0, 2.0),
+                                   Vector3::new(2.0, 4.0, 4.0),
+                                   Vector3::new(-1.0, -2.0, -2.0));
+        let svd = m.svd();
+        assert_relative_eq!(svd.singular_values, Vector3::new(54.0f64.sqrt(), 0.0, 0.0), epsilon = EPSILON);
+        assert_relative_eq!(svd.u.transpose() * svd.u, Matrix3::identity(), epsilon = EPSILON);
+        assert_relative_eq!(svd.v.transpose() * svd.v, Matrix3::identity(), epsilon = EPSILON);
+        assert_relative_eq!(svd.u * Matrix3::from_diagonal(svd.singular_values) * svd.v.transpose(), m,
+                            epsilon = EPSILON);
+
+        let svd = Matrix4::<f64>::zero().svd();
+        assert_eq!(svd.singular_values, Vector4::zero());
+        assert_relative_eq!(svd.u, Matrix4::identity(), epsilon = EPSILON);
+    }
+}
+
+mod symmetric_eigen {
+    use super::*;
+
+    fn reconstruct3(eigen: SymmetricEigen<Basis3<f64>, Vector3<f64>>) -> Matrix3<f64> {
+        let v: Matrix3<f64> = eigen.eigenvectors.into();
+        v * Matrix3::from_diagonal(eigen.eigenvalues) * v.transpose()
+    }
+
+    #[test]
+    fn test_matrix2() {
+        let m = Matrix2::new(2.0f64, 1.0, 1.0, 2.0);
+        let eigen = m.symmetric_eigen();
+        assert_relative_eq!(eigen.eigenvalues, Vector2::new(3.0, 1.0), epsilon = EPSILON);
+
+        let v: Matrix2<f64> = eigen.eigenvectors.into();
+        assert_relative_eq!(v.x.x.abs(), 0.5f64.sqrt(), epsilon = EPSILON);
+        assert_relative_eq!(v.x.x, v.x.y, epsilon = EPSILON);
+        assert_relative_eq!(v.determinant(), 1.0, epsilon = EPSILON);
+        assert_relative_eq!(v * Matrix2::from_diagonal(eigen.eigenvalues) * v.transpose(), m, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_matrix3() {
+        let eigen = spd3().symmetric_eigen();
+        let e = eigen.eigenvalues;
+        assert!(e.x >= e.y && e.y >= e.z);
+        assert_relative_eq!(e.sum(), spd3().trace(), epsilon = EPSILON);
+        assert_relative_eq!(reconstruct3(eigen), spd3(), epsilon = EPSILON);
+
+        let v: Matrix3<f64> = eigen.eigenvectors.into();
+        assert_relative_eq!(v.transpose() * v, Matrix3::identity(), epsilon = EPSILON);
+        assert_relative_eq!(v.determinant(), 1.0, epsilon = EPSILON);
+        for i in 0..3 {
+            assert_relative_eq!(spd3() * v[i], v[i] * e[i], epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rotated_diagonal() {
+        // An inertia tensor with known principal axes
+        let rot = Matrix3::from(Quaternion::from(Euler::new(Deg(20.0), Deg(-35.0), Deg(70.0))));
+        let m = rot * Matrix3::from_diagonal(Vector3::new(1.0, 5.0, -2.0)) * rot.transpose();
+        let eigen = m.symmetric_eigen();
+        assert_relative_eq!(eigen.eigenvalues, Vector3::new(5.0, 1.0, -2.0), epsilon = EPSILON);
+        assert_relative_eq!(reconstruct3(eigen), m, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_repeated_eigenvalues() {
+        let eigen = Matrix3::from_value(2.0f64).symmetric_eigen();
+        assert_eq!(eigen.eigenvalues, Vector3::new(2.0, 2.0, 2.0));
+        assert_relative_eq!(reconstruct3(eigen), Matrix3::from_value(2.0), epsilon = EPSILON);
+
+        let m = Matrix3::new(2.0, 1.0, 1.0,
+                             1.0, 2.0, 1.0,
+                             1.0, 1.0, 2.0);
+        let eigen = m.symmetric_eigen();
+        assert_relative_eq!(eigen.eigenvalues, Vector3::new(4.0, 1.0, 1.0), epsilon = EPSILON);
+        assert_relative_eq!(reconstruct3(eigen), m, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_matrix4() {
+        let m = a4().transpose() * a4();
+        let eigen = m.symmetric_eigen();
+        let e = eigen.eigenvalues;
+        assert!(e.x >= e.y && e.y >= e.z && e.z >= e.w);
+
+        let v = eigen.eigenvectors;
+        assert_relative_eq!(v.transpose() * v, Matrix4::identity(), epsilon = EPSILON);
+        assert_relative_eq!(v.determinant(), 1.0, epsilon = EPSILON);
+        assert_relative_eq!(v * Matrix4::from_diagonal(e) * v.transpose(), m, epsilon = 1.0e-9);
+    }
+}
+
+mod decompose {
+    use super::*;
+
+    fn rotation() -> Quaternion<f64> {
+        Quaternion::from(Euler::new(Deg(30.0), Deg(-45.0), Deg(110.0)))
+    }
+
+    fn trs(scale: Vector3<f64>) -> Trs<f64> {
+        Trs { translation: Vector3::new(1.0, -2.0, 3.0), rotation: rotation(), scale }
+    }
+
+    fn same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) -> bool {
+        relative_eq!(a, b, epsilon = EPSILON) || relative_eq!(a, -b, epsilon = EPSILON)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let expected = trs(Vector3::new(2.0, 0.5, 3.0));
+        let m = Matrix4::from(expected);
+        let decomposed = m.decompose().unwrap();
+        assert_relative_eq!(decomposed.translation, expected.translation, epsilon = EPSILON);
+        assert_relative_eq!(decomposed.scale, expected.scale, epsilon = EPSILON);
+        assert!(same_rotation(decomposed.rotation, expected.rotation));
+        assert_relative_eq!(Matrix4::from(decomposed), m, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_negative_scale() {
+        let m = Matrix4::from(trs(Vector3::new(2.0, -0.5, 3.0)));
+        let decomposed = m.decompose().unwrap();
+        assert!(decomposed.scale.x < 0.0);
+        assert_relative_eq!(decomposed.scale.y, 0.5, epsilon = EPSILON);
+        assert_relative_eq!(decomposed.rotation.magnitude(), 1.0, epsilon = EPSILON);
+        assert_relative_eq!(Matrix4::from(decomposed), m, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_uniform_scale() {
+        let m = Matrix4::from(trs(Vector3::new(2.0, 2.0, 2.0)));
+        let dec = m.decompose().unwrap().to_decomposed().unwrap();
+        assert_relative_eq!(dec.scale, 2.0, epsilon = EPSILON);
+        assert_relative_eq!(Matrix4::from(dec), m, epsilon = EPSILON);
+
+        // A reflection through the origin
+        let m = Matrix4::from(trs(Vector3::new(-2.0, -2.0, -2.0)));
+        let dec = m.decompose().unwrap().to_decomposed().unwrap();
+        assert_relative_eq!(dec.scale, -2.0, epsilon = EPSILON);
+        assert_relative_eq!(Matrix4::from(dec), m, epsilon = EPSILON);
+
+        assert!(trs(Vector3::new(2.0, 1.0, 2.0)).to_decomposed().is_none());
+    }
+
+    #[test]
+    fn test_small_scale() {
+        let m = Matrix4::from_nonuniform_scale(1.0e-8f32, 2.0e-8, 1.0);
+        let trs = m.decompose().unwrap();
+        assert_relative_eq!(trs.scale, Vector3::new(1.0e-8, 2.0e-8, 1.0));
+        assert_relative_eq!(trs.rotation, Quaternion::one());
+    }
+
+    #[test]
+    fn test_errors() {
+        let shear = Matrix4::from_cols(Vector4::unit_x(), Vector4::new(0.5, 1.0, 0.0, 0.0),
+                                       Vector4::unit_z(), Vector4::unit_w());
+        assert_eq!(shear.decompose(), Err(DecomposeError::Shear));
+        assert_eq!(Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0).decompose(), Err(DecomposeError::Singular));
+        assert_eq!(Matrix4::from_nonuniform_scale(0.0, 0.0, 0.0).decompose(), Err(DecomposeError::Singular));
+        assert_eq!(perspective(Deg(60.0), 1.0, 0.1, 100.0).decompose(), Err(DecomposeError::NotAffine));
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn rotation(axis: Vector3<f64>, angle: f64) -> Quaternion<f64> {
+    Quaternion::from_axis_angle(axis.normalize(), Rad(angle))
+}
+
+fn sample() -> DualQuaternion<f64> {
+    DualQuaternion::from_rotation_translation(rotation(vec3(1.0, 2.0, -0.5), 0.8), vec3(1.0, -2.0, 3.0))
+}
+
+#[test]
+fn test_rotation_translation() {
+    let rot = rotation(vec3(0.0, 1.0, 1.0), 1.3);
+    let disp = vec3(4.0, 5.0, -6.0);
+    let dq = DualQuaternion::from_rotation_translation(rot, disp);
+
+    assert_eq!(dq.rotation(), rot);
+    assert_relative_eq!(dq.translation(), disp, epsilon = 1.0e-12);
+
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let dec = Decomposed { scale: 1.0, rot, disp };
+    assert_relative_eq!(dq.transform_point(p), dec.transform_point(p), epsilon = 1.0e-12);
+    assert_relative_eq!(DualQuaternion::from_decomposed(dec).unwrap(), dq);
+}
+
+#[test]
+fn test_from_decomposed_with_scale() {
+    let dec = Decomposed { scale: 2.0, rot: Quaternion::one(), disp: vec3(1.0, 0.0, 0.0) };
+    assert!(DualQuaternion::from_decomposed(dec).is_none());
+}
+
+#[test]
+fn test_mul_composes_transforms() {
+    let a = sample();
+    let b = DualQuaternion::from_rotation_translation(rotation(vec3(0.0, 0.0, 1.0), -2.1), vec3(0.5, 0.0, 1.0));
+    let p = Point3::new(-1.0, 0.5, 2.0);
+    assert_relative_eq!((a * b).transform_point(p), a.transform_point(b.transform_point(p)), epsilon = 1.0e-12);
+    assert_relative_eq!(a.concat(&b), a * b);
+}
+
+#[test]
+fn test_conjugate_is_inverse() {
+    let dq = sample();
+    let p = Point3::new(3.0, -1.0, 2.0);
+    assert_relative_eq!(dq.conjugate().transform_point(dq.transform_point(p)), p, epsilon = 1.0e-12);
+    assert_relative_eq!(dq * dq.conjugate(), DualQuaternion::one(), epsilon = 1.0e-12);
+    assert_relative_eq!(dq.inverse_transform().unwrap(), dq.conjugate(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_combined_conjugate_transforms_points() {
+    let dq = sample();
+    let p = Point3::new(3.0, -1.0, 2.0);
+    let point = DualQuaternion::new(Quaternion::one(), Quaternion::from_sv(0.0, p.to_vec()));
+    let transformed = dq * point * dq.combined_conjugate();
+    assert_relative_eq!(transformed.dual.v, dq.transform_point(p).to_vec(), epsilon = 1.0e-12);
+    assert_eq!(dq.dual_conjugate(), DualQuaternion::new(dq.real, -dq.dual));
+}
+
+#[test]
+fn test_normalize() {
+    let dq = sample();
+    let scaled = DualQuaternion::new(dq.real * 3.0, dq.dual * 3.0 + dq.real * 0.1);
+    assert_relative_eq!(scaled.normalize(), dq, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_matrix4() {
+    let dq = sample();
+    let m = Matrix4::from(dq);
+    let p = Point3::new(0.5, 1.5, -2.5);
+    assert_relative_eq!(m.transform_point(p), dq.transform_point(p), epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(Isometry3::from(dq)), m, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_sclerp() {
+    let a = sample();
+    let b = DualQuaternion::from_rotation_translation(rotation(vec3(0.0, 1.0, 0.0), 2.0), vec3(-3.0, 1.0, 0.0));
+
+    assert_relative_eq!(a.sclerp(b, 0.0), a, epsilon = 1.0e-12);
+    assert_relative_eq!(a.sclerp(b, 1.0), b, epsilon = 1.0e-12);
+
+    // The real part follows the same path as a slerp
+    let mid = a.sclerp(b, 0.5);
+    assert_relative_eq!(mid.real, a.real.slerp(b.real, 0.5), epsilon = 1.0e-12);
+
+    // Half of the relative screw motion, applied twice, gives the full motion
+    let half = a.conjugate() * mid;
+    assert_relative_eq!(a * half * half, b, epsilon = 1.0e-12);
+
+    // Negated dual quaternions represent the same transform
+    assert_relative_eq!(a.sclerp(-b, 0.5), mid, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_sclerp_translation() {
+    let a = DualQuaternion::from_translation(vec3(0.0, 0.0, 0.0));
+    let b = DualQuaternion::from_translation(vec3(2.0, 4.0, -6.0));
+    assert_relative_eq!(a.sclerp(b, 0.25).translation(), vec3(0.5, 1.0, -1.5), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_blend() {
+    let a = sample();
+    let b = DualQuaternion::from_rotation_translation(rotation(vec3(0.0, 1.0, 0.0), 0.4), vec3(-3.0, 1.0, 0.0));
+
+    assert_relative_eq!(DualQuaternion::blend(vec![(a, 1.0)]).unwrap(), a, epsilon = 1.0e-12);
+    assert_relative_eq!(DualQuaternion::blend(vec![(a, 0.3), (b, 0.7)]).unwrap(),
+                        DualQuaternion::blend(vec![(a, 0.3), (-b, 0.7)]).unwrap(),
+                        epsilon = 1.0e-12);
+
+    let blended = DualQuaternion::blend(vec![(a, 0.5), (b, 0.5)]).unwrap();
+    assert_relative_eq!(blended.real.magnitude(), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(blended.real.dot(blended.dual), 0.0, epsilon = 1.0e-12);
+
+    assert!(DualQuaternion::<f64>::blend(vec![]).is_none());
+    assert!(DualQuaternion::blend(vec![(a, 1.0), (a, -1.0)]).is_none());
+}
+
+#[test]
+fn test_isometry() {
+    let iso = Isometry3::new(rotation(vec3(1.0, 0.0, 0.0), 0.6), vec3(1.0, 2.0, 3.0));
+    let dq = DualQuaternion::from(iso);
+    let p = Point3::new(-2.0, 0.0, 1.0);
+    assert_relative_eq!(dq.transform_point(p), iso.transform_point(p), epsilon = 1.0e-12);
+    assert_relative_eq!(Isometry3::from(dq), iso, epsilon = 1.0e-12);
+}
@@ -0,0 +1,134 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const SEQUENCES: [EulerSequence; 12] = [
+    EulerSequence::XYZ, EulerSequence::XZY, EulerSequence::YXZ,
+    EulerSequence::YZX, EulerSequence::ZXY, EulerSequence::ZYX,
+    EulerSequence::XYX, EulerSequence::XZX, EulerSequence::YXY,
+    EulerSequence::YZY, EulerSequence::ZXZ, EulerSequence::ZYZ,
+];
+
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+fn is_proper(sequence: EulerSequence) -> bool {
+    match sequence {
+        EulerSequence::XYX | EulerSequence::XZX |
+        EulerSequence::YXY | EulerSequence::YZY |
+        EulerSequence::ZXZ | EulerSequence::ZYZ => true,
+        _ => false,
+    }
+}
+
+fn angles(sequence: EulerSequence, frame: EulerFrame, a: f64, b: f64, c: f64) -> EulerAngles<Rad<f64>> {
+    EulerAngles::new(sequence, frame, Rad(a), Rad(b), Rad(c))
+}
+
+#[test]
+fn test_xyz_matches_euler() {
+    let euler = Euler::new(Rad(0.3f64), Rad(-1.1), Rad(2.0));
+    let angles = EulerAngles::from(euler);
+    assert_ulps_eq!(Quaternion::from(angles), Quaternion::from(euler));
+    assert_relative_eq!(Matrix3::from(angles), Matrix3::from(euler), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_extrinsic_is_reversed_intrinsic() {
+    for &sequence in SEQUENCES.iter() {
+        let e = angles(sequence, EulerFrame::Extrinsic, 0.3, 1.2, -0.7);
+        let expected = Matrix3::from(Quaternion::from(e));
+        let (a, b, c) = (Rad(-0.7), Rad(1.2), Rad(0.3));
+        let reversed = match sequence {
+            EulerSequence::XYZ => EulerAngles::intrinsic(EulerSequence::ZYX, a, b, c),
+            EulerSequence::ZYX => EulerAngles::intrinsic(EulerSequence::XYZ, a, b, c),
+            EulerSequence::YXZ => EulerAngles::intrinsic(EulerSequence::ZXY, a, b, c),
+            EulerSequence::ZXY => EulerAngles::intrinsic(EulerSequence::YXZ, a, b, c),
+            EulerSequence::XZY => EulerAngles::intrinsic(EulerSequence::YZX, a, b, c),
+            EulerSequence::YZX => EulerAngles::intrinsic(EulerSequence::XZY, a, b, c),
+            _ => EulerAngles::intrinsic(sequence, a, b, c),
+        };
+        assert_relative_eq!(Matrix3::from(reversed), expected, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_intrinsic_axes() {
+    // Yaw around z, then pitch around the new y axis
+    let e = EulerAngles::intrinsic(EulerSequence::ZYX, Deg(90.0), Deg(90.0), Deg(0.0));
+    let q = Quaternion::from(e);
+    assert_relative_eq!(q.rotate_vector(Vector3::unit_x()), -Vector3::unit_z(), epsilon = 1.0e-12);
+
+    // Yaw around z, then pitch around the fixed y axis
+    let e = EulerAngles::extrinsic(EulerSequence::ZYX, Deg(90.0), Deg(90.0), Deg(0.0));
+    let q = Quaternion::from(e);
+    assert_relative_eq!(q.rotate_vector(Vector3::unit_x()), Vector3::unit_y(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_conversions_agree() {
+    for &sequence in SEQUENCES.iter() {
+        for &frame in FRAMES.iter() {
+            let e = angles(sequence, frame, 0.4, 0.9, -2.5);
+            let m = Matrix3::from(e);
+            assert_relative_eq!(Matrix3::from(Quaternion::from(e)), m, epsilon = 1.0e-12);
+            assert_relative_eq!(Matrix3::from(Basis3::from(e)), m, epsilon = 1.0e-12);
+            assert_relative_eq!(Matrix4::from(e), Matrix4::from(m), epsilon = 1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let cases = [(0.0, 0.0, 0.0), (0.3, 0.5, 0.7), (-2.9, 1.2, 3.0), (1.0, -1.5, -0.2)];
+    for &sequence in SEQUENCES.iter() {
+        for &frame in FRAMES.iter() {
+            for &(a, b, c) in cases.iter() {
+                // Proper Euler sequences keep the second angle positive
+                let b = if is_proper(sequence) { b + 1.6 } else { b };
+                let e = angles(sequence, frame, a, b, c);
+
+                let from_matrix = EulerAngles::from_matrix3(Matrix3::from(e), sequence, frame);
+                assert_relative_eq!(from_matrix, e, epsilon = 1.0e-9);
+
+                let from_quaternion = EulerAngles::from_quaternion(Quaternion::from(e), sequence, frame);
+                assert_relative_eq!(from_quaternion, e, epsilon = 1.0e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gimbal_lock() {
+    for &sequence in SEQUENCES.iter() {
+        let locks = if is_proper(sequence) { [0.0, PI] } else { [FRAC_PI_2, -FRAC_PI_2] };
+        for &frame in FRAMES.iter() {
+            for &b in locks.iter() {
+                let e = angles(sequence, frame, 0.5, b, 0.25);
+                let m = Matrix3::from(e);
+                let extracted = EulerAngles::from_matrix3(m, sequence, frame);
+
+                assert_eq!(extracted.c, Rad(0.0));
+                assert_relative_eq!(extracted.b, Rad(b), epsilon = 1.0e-9);
+                assert_relative_eq!(Matrix3::from(extracted), m, epsilon = 1.0e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn ortho_frustum() -> Frustum<f64> {
+    Frustum::from_matrix4(ortho(-1.0, 1.0, -2.0, 2.0, 1.0, 10.0))
+}
+
+fn perspective_frustum() -> Frustum<f64> {
+    Frustum::from_matrix4(perspective(Deg(90.0), 1.0, 1.0, 10.0))
+}
+
+#[test]
+fn test_ortho_planes() {
+    let expected = Frustum::new(Plane::new(Vector3::unit_x(), -1.0),
+                                Plane::new(-Vector3::unit_x(), -1.0),
+                                Plane::new(Vector3::unit_y(), -2.0),
+                                Plane::new(-Vector3::unit_y(), -2.0),
+                                Plane::new(-Vector3::unit_z(), 1.0),
+                                Plane::new(Vector3::unit_z(), -10.0));
+    assert_ulps_eq!(ortho_frustum(), expected);
+}
+
+#[test]
+fn test_perspective_planes() {
+    let f = perspective_frustum();
+    let s = 0.5f64.sqrt();
+    assert_relative_eq!(f.left, Plane::new(Vector3::new(s, 0.0, -s), 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(f.top, Plane::new(Vector3::new(0.0, -s, -s), 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(f.near, Plane::new(-Vector3::unit_z(), 1.0), epsilon = 1.0e-12);
+    assert_relative_eq!(f.far, Plane::new(Vector3::unit_z(), -10.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_view_projection() {
+    // Moving the camera moves the extracted planes into world space
+    let view = Matrix4::from_translation(Vector3::new(0.0, 0.0, -5.0));
+    let proj = ortho(-1.0, 1.0, -2.0, 2.0, 1.0, 10.0);
+    let f = Frustum::from(proj * view);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, 0.0)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -3.0)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, 5.0)), Relation::Outside);
+}
+
+#[test]
+fn test_infinite_far_plane() {
+    let f = Frustum::from_matrix4(perspective_infinite(Deg(90.0), 1.0, 1.0));
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -1.0e12)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -0.5)), Relation::Outside);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(0.0, 0.0, -1.0e6), 1.0)), Relation::Inside);
+    assert_eq!(f.far.normal, Vector3::zero());
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0e9), Point3::new(1.0, 1.0, -1.0e8));
+    assert_eq!(f.contains_aabb(&aabb), Relation::Inside);
+    let behind = Aabb3::new(Point3::new(-1.0, -1.0, 1.0), Point3::new(1.0, 1.0, 2.0));
+    assert_eq!(f.contains_aabb(&behind), Relation::Outside);
+}
+
+#[test]
+fn test_reverse_z() {
+    let convention = Convention::opengl().reverse_z();
+    let f = Frustum::from_matrix4_in(perspective_reverse_z(Deg(90.0), 1.0, 1.0, 10.0), convention);
+    assert_relative_eq!(f, perspective_frustum(), epsilon = 1.0e-12);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -5.0)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -0.5)), Relation::Outside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -11.0)), Relation::Outside);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(0.0, 0.0, -10.0), 1.0)), Relation::Intersecting);
+
+    // The same frustum, built by converting an OpenGL projection
+    let g = Frustum::from_matrix4_in(convention.perspective(Deg(90.0), 1.0, 1.0, 10.0), convention);
+    assert_relative_eq!(g, f, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_reverse_z_infinite() {
+    let proj = perspective_reverse_z_infinite(Deg(90.0), 1.0, 1.0);
+    let f = Frustum::from_matrix4_in(proj, Convention::opengl().reverse_z());
+    assert_relative_eq!(f.near, Plane::new(-Vector3::unit_z(), 1.0), epsilon = 1.0e-12);
+    assert_eq!(f.far.normal, Vector3::zero());
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -1.0e12)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -0.5)), Relation::Outside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, 5.0)), Relation::Outside);
+    assert_eq!(f.contains_point(Point3::new(2.0e6, 0.0, -1.0e6)), Relation::Outside);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(0.0, 0.0, -1.0e6), 1.0)), Relation::Inside);
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0e9), Point3::new(1.0, 1.0, -1.0e8));
+    assert_eq!(f.contains_aabb(&aabb), Relation::Inside);
+}
+
+#[test]
+fn test_conventions() {
+    for &convention in [Convention::opengl(), Convention::vulkan(), Convention::direct3d()].iter() {
+        let f = Frustum::from_matrix4_in(convention.perspective(Deg(90.0), 1.0, 1.0, 10.0), convention);
+        let z = match convention.handedness {
+            Handedness::Right => -1.0,
+            Handedness::Left => 1.0,
+        };
+        assert_relative_eq!(f.near, Plane::new(Vector3::new(0.0, 0.0, z), 1.0), epsilon = 1.0e-12);
+        assert_relative_eq!(f.far, Plane::new(Vector3::new(0.0, 0.0, -z), -10.0), epsilon = 1.0e-12);
+        assert_relative_eq!(f.top.normal.y, -0.5f64.sqrt(), epsilon = 1.0e-12);
+        assert_eq!(f.contains_point(Point3::new(0.0, 4.0, 5.0 * z)), Relation::Inside);
+        assert_eq!(f.contains_point(Point3::new(0.0, 6.0, 5.0 * z)), Relation::Outside);
+    }
+}
+
+#[test]
+fn test_contains_point() {
+    let f = perspective_frustum();
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -5.0)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(4.0, 0.0, -5.0)), Relation::Inside);
+    assert_eq!(f.contains_point(Point3::new(6.0, 0.0, -5.0)), Relation::Outside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -0.5)), Relation::Outside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, -11.0)), Relation::Outside);
+    assert_eq!(f.contains_point(Point3::new(0.0, 0.0, 5.0)), Relation::Outside);
+}
+
+#[test]
+fn test_contains_sphere() {
+    let f = perspective_frustum();
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0)), Relation::Inside);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(0.0, 0.0, -10.0), 1.0)), Relation::Intersecting);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(5.0, 0.0, -5.0), 1.0)), Relation::Intersecting);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(0.0, 0.0, -12.0), 1.0)), Relation::Outside);
+    assert_eq!(f.contains_sphere(&Sphere::new(Point3::new(8.0, 0.0, -5.0), 1.0)), Relation::Outside);
+}
+
+#[test]
+fn test_contains_aabb() {
+    let f = ortho_frustum();
+    let aabb = |min: Point3<f64>, max: Point3<f64>| Aabb3::new(min, max);
+    assert_eq!(f.contains_aabb(&aabb(Point3::new(-0.5, -0.5, -5.0), Point3::new(0.5, 0.5, -4.0))),
+               Relation::Inside);
+    assert_eq!(f.contains_aabb(&aabb(Point3::new(0.5, -0.5, -5.0), Point3::new(1.5, 0.5, -4.0))),
+               Relation::Intersecting);
+    assert_eq!(f.contains_aabb(&aabb(Point3::new(-0.5, -0.5, -11.0), Point3::new(0.5, 0.5, -9.0))),
+               Relation::Intersecting);
+    assert_eq!(f.contains_aabb(&aabb(Point3::new(1.5, -0.5, -5.0), Point3::new(2.5, 0.5, -4.0))),
+               Relation::Outside);
+    assert_eq!(f.contains_aabb(&aabb(Point3::new(-0.5, -0.5, 1.0), Point3::new(0.5, 0.5, 2.0))),
+               Relation::Outside);
+    // A box enclosing the whole frustum
+    assert_eq!(f.contains_aabb(&aabb(Point3::new(-5.0, -5.0, -20.0), Point3::new(5.0, 5.0, 5.0))),
+               Relation::Intersecting);
+}
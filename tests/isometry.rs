@@ -0,0 +1,146 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+use cgmath::*;
+
+const EPSILON: f64 = 1.0e-12;
+
+fn isometry3() -> Isometry3<f64> {
+    Isometry3::new(Quaternion::from(Euler::new(Deg(30.0), Deg(-45.0), Deg(110.0))),
+                   Vector3::new(6.0, -7.0, 8.0))
+}
+
+fn similarity3() -> Similarity3<f64> {
+    let iso = isometry3();
+    Similarity3::new(2.5, iso.rotation, iso.translation)
+}
+
+#[test]
+fn test_isometry3_inverse() {
+    let iso = isometry3();
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_relative_eq!(iso.inverse().transform_point(iso.transform_point(p)), p, epsilon = EPSILON);
+    assert_relative_eq!(iso.concat(&iso.inverse()), Isometry3::one(), epsilon = EPSILON);
+    assert_relative_eq!(Matrix4::from(iso.inverse()), Matrix4::from(iso).invert().unwrap(), epsilon = EPSILON);
+}
+
+#[test]
+fn test_isometry2() {
+    let iso = Isometry2::new(Basis2::from_angle(Deg(60.0)), Vector2::new(1.0, -2.0));
+    let p = Point2::new(3.0, 4.0);
+    assert_relative_eq!(iso.transform_point(p), Matrix3::from(iso).transform_point(p), epsilon = EPSILON);
+    assert_relative_eq!(iso.inverse().transform_point(iso.transform_point(p)), p, epsilon = EPSILON);
+    assert_relative_eq!(iso.transform_vector(Vector2::unit_x()).magnitude(), 1.0, epsilon = EPSILON);
+}
+
+#[test]
+fn test_similarity3() {
+    let sim = similarity3();
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let m = Matrix4::from(sim);
+    assert_relative_eq!(sim.transform_point(p), m.transform_point(p), epsilon = EPSILON);
+    assert_relative_eq!(Matrix4::from(sim.inverse()), m.invert().unwrap(), epsilon = EPSILON);
+    assert_relative_eq!(Matrix4::from(sim.concat(&sim)), m * m, epsilon = EPSILON);
+    assert!(Similarity3::<f64>::from_scale(0.0).inverse_transform().is_none());
+}
+
+#[test]
+fn test_decomposed_conversions() {
+    let sim = similarity3();
+    let dec = Decomposed::from(sim);
+    assert_eq!(dec.scale, 2.5);
+    assert_relative_eq!(Similarity3::from(dec), sim);
+    assert_relative_eq!(Matrix4::from(dec), Matrix4::from(sim));
+
+    let iso = isometry3();
+    assert_relative_eq!(Similarity3::from(Decomposed::from(iso)).to_isometry().unwrap(), iso);
+    assert!(sim.to_isometry().is_none());
+}
+
+#[test]
+fn test_from_matrix4() {
+    let sim = similarity3();
+    let extracted = Similarity3::from_matrix4(Matrix4::from(sim)).unwrap();
+    assert_relative_eq!(Matrix4::from(extracted), Matrix4::from(sim), epsilon = EPSILON);
+    assert_relative_eq!(extracted.scale, 2.5, epsilon = EPSILON);
+
+    let extracted = Isometry3::from_matrix4(Matrix4::from(isometry3())).unwrap();
+    assert_relative_eq!(Matrix4::from(extracted), Matrix4::from(isometry3()), epsilon = EPSILON);
+
+    assert!(Isometry3::from_matrix4(Matrix4::from(sim)).is_none());
+    assert!(Similarity3::from_matrix4(Matrix4::from_nonuniform_scale(1.0, 2.0, 1.0)).is_none());
+}
+
+#[test]
+fn test_isometry_from_decomposed() {
+    let iso = isometry3();
+    assert_relative_eq!(Isometry3::from_decomposed(Decomposed::from(iso)).unwrap(), iso);
+    assert!(Isometry3::from_decomposed(Decomposed::from(similarity3())).is_none());
+
+    let iso = Isometry2::new(Basis2::from_angle(Deg(60.0)), Vector2::new(1.0, -2.0));
+    let dec = Decomposed::from(iso);
+    assert_eq!(dec.scale, 1.0);
+    assert_relative_eq!(Isometry2::from_decomposed(dec).unwrap(), iso);
+    let scaled = Decomposed { scale: 2.0, ..dec };
+    assert!(Isometry2::from_decomposed(scaled).is_none());
+    assert_relative_eq!(Similarity2::from(scaled), Similarity2::new(2.0, iso.rotation, iso.translation));
+    assert_relative_eq!(Decomposed::from(Similarity2::from(scaled)), scaled);
+}
+
+#[test]
+fn test_from_matrix3() {
+    let sim = Similarity2::new(0.25, Basis2::from_angle(Deg(-135.0)), Vector2::new(3.0, 4.0));
+    let extracted = Similarity2::from_matrix3(Matrix3::from(sim)).unwrap();
+    assert_relative_eq!(extracted, sim, epsilon = EPSILON);
+    assert!(Isometry2::from_matrix3(Matrix3::from(sim)).is_none());
+
+    let iso = Isometry2::new(Basis2::from_angle(Deg(170.0)), Vector2::new(-1.0, 2.0));
+    let extracted = Isometry2::from_matrix3(Matrix3::from(iso)).unwrap();
+    assert_relative_eq!(extracted, iso, epsilon = EPSILON);
+    assert_relative_eq!(Matrix3::from(extracted), Matrix3::from(iso), epsilon = EPSILON);
+
+    let reflection = Matrix3::from_diagonal(Vector3::new(-1.0, 1.0, 1.0));
+    assert!(Similarity2::from_matrix3(reflection).is_none());
+    assert!(Similarity2::from_matrix3(Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 1.0))).is_none());
+    assert!(Similarity2::from_matrix3(Matrix3::from_diagonal(Vector3::new(0.0, 0.0, 1.0))).is_none());
+    let shear = Matrix3::new(1.0, 0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0);
+    assert!(Similarity2::from_matrix3(shear).is_none());
+}
+
+#[test]
+fn test_look_at() {
+    let eye = Point3::new(0.0f64, 0.0, -5.0);
+    let center = Point3::new(0.0f64, 0.0, 0.0);
+    let up = Vector3::new(1.0f64, 0.0, 0.0);
+    let iso: Isometry3<f64> = Transform::look_at(eye, center, up);
+    let dec: Decomposed<Vector3<f64>, Quaternion<f64>> = Transform::look_at(eye, center, up);
+    assert_ulps_eq!(Decomposed::from(iso), dec);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize() {
+    let sim = similarity3();
+    let serialized = serde_json::to_string(&sim).unwrap();
+    let deserialized: Similarity3<f64> = serde_json::from_str(&serialized).unwrap();
+    assert_ulps_eq!(sim, deserialized);
+}
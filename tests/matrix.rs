@@ -782,3 +782,90 @@ pub mod matrix4 {
         }
     }
 }
+
+pub mod nonsquare {
+    use cgmath::*;
+    use cgmath::conv::*;
+
+    // 2 rows and 3 columns
+    const A: Matrix2x3<f64> = Matrix2x3 { x: Vector2 { x: 1.0f64, y: 4.0f64 },
+                                          y: Vector2 { x: 2.0f64, y: 5.0f64 },
+                                          z: Vector2 { x: 3.0f64, y: 6.0f64 } };
+
+    fn b() -> Matrix3x4<f64> {
+        Matrix3x4::new(1.0, 0.0, 2.0,
+                       -1.0, 3.0, 1.0,
+                       0.5, 1.0, 0.0,
+                       2.0, -2.0, 4.0)
+    }
+
+    #[test]
+    fn test_row_and_transpose() {
+        assert_eq!(A.row(0), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(A.row(1), Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(A.transpose(), Matrix3x2::new(1.0, 2.0, 3.0,
+                                                 4.0, 5.0, 6.0));
+        assert_eq!(A.transpose().transpose(), A);
+        assert_eq!(b().transpose().transpose(), b());
+        assert_eq!(b().transpose().row(3), b().w);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(A + A, A * 2.0);
+        assert_eq!(A - A, Matrix2x3::zero());
+        assert_eq!(-A, A * -1.0);
+        assert_eq!(2.0 * A, A * 2.0);
+        assert_eq!([A, A, A].iter().sum::<Matrix2x3<f64>>(), A * 3.0);
+        assert_eq!(A.cast::<f32>().unwrap()[2], Vector2::new(3.0f32, 6.0));
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(A * Vector3::new(1.0, 1.0, 1.0), Vector2::new(6.0, 15.0));
+        assert_eq!(A.transpose() * Vector2::new(1.0, -1.0), Vector3::new(-3.0, -3.0, -3.0));
+        assert_eq!(b() * Vector4::unit_w(), b().w);
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let m: Matrix2<f64> = A * A.transpose();
+        assert_eq!(m, Matrix2::new(14.0, 32.0, 32.0, 77.0));
+        assert_eq!(m, m.transpose());
+
+        let m: Matrix3<f64> = A.transpose() * A;
+        assert_eq!(m.row(0), Vector3::new(17.0, 22.0, 27.0));
+
+        // (2 x 3) * (3 x 4) = (2 x 4), and agrees with the transposed product
+        let p: Matrix2x4<f64> = A * b();
+        assert_eq!(p.transpose(), b().transpose() * A.transpose());
+        for c in 0..4 {
+            assert_eq!(p[c], A * b()[c]);
+        }
+
+        // Appending a row to an affine 3 x 4 matrix gives the equivalent `Matrix4`
+        let m4 = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_scale(2.0);
+        let affine: Matrix3x4<f64> = Affine3::from_matrix4(m4).unwrap().into();
+        assert_eq!(affine * m4, Matrix3x4::from(Affine3::from_matrix4(m4 * m4).unwrap()));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut m = A;
+        m.swap_rows(0, 1);
+        assert_eq!(m.row(0), A.row(1));
+        m.swap_columns(0, 2);
+        assert_eq!(m.x, Vector2::new(6.0, 3.0));
+        m.swap_elements((0, 0), (1, 1));
+        assert_eq!(m.x.x, 2.0);
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        assert_eq!(array2x3(A), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(Matrix2x3::from(array2x3(A)), A);
+        let flat: &[f64; 6] = A.as_ref();
+        assert_eq!(flat, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(array4x3(b().transpose())[2], [2.0, 1.0, 0.0, 4.0]);
+    }
+}
@@ -15,7 +15,25 @@
 
 extern crate cgmath;
 
-use cgmath::{Vector4, ortho, Matrix4};
+#[macro_use]
+extern crate approx;
+
+use cgmath::{Convention, Deg, DepthRange, Handedness, InnerSpace, Point2, Point3, Vector3, Vector4, Viewport, frustum,
+             ortho, perspective, perspective_infinite, perspective_reverse_z, perspective_reverse_z_infinite,
+             pick_ray, project, unproject, Matrix4};
+
+/// Project a point on the view axis at `distance` in front of the camera, and
+/// return its depth after the perspective divide.
+fn depth(proj: Matrix4<f64>, distance: f64) -> f64 {
+    let clip = proj * Vector4::new(0.0, 0.0, -distance, 1.0);
+    clip.z / clip.w
+}
+
+/// Project a view space point into normalized device coordinates.
+fn ndc(proj: Matrix4<f64>, x: f64, y: f64, z: f64) -> Vector3<f64> {
+    let clip = proj * Vector4::new(x, y, z, 1.0);
+    clip.truncate() / clip.w
+}
 
 #[test]
 fn test_ortho_scale() {
@@ -67,3 +85,166 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_perspective_infinite() {
+    let proj = perspective_infinite(Deg(60.0), 1.5, 0.1);
+    assert_ulps_eq!(depth(proj, 0.1), -1.0);
+    assert!(depth(proj, 1.0e12) < 1.0);
+    assert_relative_eq!(depth(proj, 1.0e12), 1.0, epsilon = 1.0e-9);
+
+    // The x and y mapping is the same as for a finite far plane
+    let finite = perspective(Deg(60.0), 1.5, 0.1, 100.0);
+    assert_eq!(proj.x, finite.x);
+    assert_eq!(proj.y, finite.y);
+}
+
+#[test]
+fn test_perspective_reverse_z() {
+    let proj = perspective_reverse_z(Deg(60.0), 1.5, 0.1, 100.0);
+    assert_ulps_eq!(depth(proj, 0.1), 1.0);
+    assert_ulps_eq!(depth(proj, 100.0), 0.0);
+    assert!(depth(proj, 1.0) > depth(proj, 2.0));
+}
+
+#[test]
+fn test_perspective_reverse_z_infinite() {
+    let proj = perspective_reverse_z_infinite(Deg(60.0), 1.5, 0.1);
+    assert_ulps_eq!(depth(proj, 0.1), 1.0);
+    assert!(depth(proj, 1.0e12) > 0.0);
+    assert_relative_eq!(depth(proj, 1.0e12), 0.0, epsilon = 1.0e-9);
+    assert!(depth(proj, 1.0) > depth(proj, 2.0));
+}
+
+#[test]
+fn test_default_convention() {
+    let gl = Convention::default();
+    assert_eq!(gl, Convention::opengl());
+    assert_eq!(gl.perspective(Deg(60.0), 1.5, 0.1, 100.0), perspective(Deg(60.0), 1.5, 0.1, 100.0));
+    assert_eq!(gl.frustum(-1.0, 2.0, -3.0, 4.0, 0.5, 50.0), frustum(-1.0, 2.0, -3.0, 4.0, 0.5, 50.0));
+    assert_eq!(gl.ortho(-1.0, 2.0, -3.0, 4.0, 0.5, 50.0), ortho(-1.0, 2.0, -3.0, 4.0, 0.5, 50.0));
+
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let center = Point3::new(-2.0, 0.5, 1.0);
+    assert_eq!(gl.look_at(eye, center, Vector3::unit_y()), Matrix4::look_at(eye, center, Vector3::unit_y()));
+}
+
+#[test]
+fn test_vulkan_perspective() {
+    let proj = Convention::vulkan().perspective(Deg(90.0), 1.0, 1.0, 10.0);
+    assert_relative_eq!(ndc(proj, 1.0, 1.0, -1.0), Vector3::new(1.0, -1.0, 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(ndc(proj, -10.0, -10.0, -10.0), Vector3::new(-1.0, 1.0, 1.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_direct3d_perspective() {
+    let proj = Convention::direct3d().perspective(Deg(90.0), 1.0, 1.0, 10.0);
+    assert_relative_eq!(ndc(proj, 1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(ndc(proj, -10.0, -10.0, 10.0), Vector3::new(-1.0, -1.0, 1.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_reverse_z_convention() {
+    let convention = Convention::opengl().reverse_z();
+    assert_eq!(convention.depth, DepthRange::OneToZero);
+    let proj = convention.perspective(Deg(60.0), 1.5, 0.1, 100.0);
+    assert_relative_eq!(proj, perspective_reverse_z(Deg(60.0), 1.5, 0.1, 100.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_direct3d_ortho() {
+    let proj = Convention::direct3d().ortho(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0);
+    assert_relative_eq!(ndc(proj, 2.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(ndc(proj, -2.0, -1.0, 5.0), Vector3::new(-1.0, -1.0, 1.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_left_handed_look_at() {
+    let d3d = Convention::direct3d();
+    assert_eq!(d3d.handedness, Handedness::Left);
+
+    // Looking down the positive `z` axis needs no change of basis
+    let view = d3d.look_at(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0), Vector3::unit_y());
+    assert_relative_eq!(view, Matrix4::from_scale(1.0), epsilon = 1.0e-12);
+
+    // The point being looked at ends up in front of the camera
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let center = Point3::new(-2.0, 0.5, 1.0);
+    let view = d3d.look_at(eye, center, Vector3::unit_y());
+    let p = view * center.to_homogeneous();
+    assert_relative_eq!(p.truncate(), Vector3::new(0.0, 0.0, (center - eye).magnitude()), epsilon = 1.0e-12);
+}
+
+fn camera() -> (Matrix4<f64>, Matrix4<f64>, Viewport<f64>) {
+    let view = Matrix4::look_at(Point3::new(0.0, 0.0, 5.0), Point3::new(0.0, 0.0, 0.0), Vector3::unit_y());
+    let proj = perspective(Deg(90.0), 2.0, 1.0, 100.0);
+    (view, proj, Viewport::new(10.0, 20.0, 800.0, 400.0))
+}
+
+#[test]
+fn test_project() {
+    let (view, proj, viewport) = camera();
+    assert_relative_eq!(project(Point3::new(0.0, 0.0, 4.0), view, proj, viewport),
+                        Point3::new(410.0, 220.0, 0.0), epsilon = 1.0e-9);
+    // The top right corner of the far plane
+    assert_relative_eq!(project(Point3::new(200.0, 100.0, -95.0), view, proj, viewport),
+                        Point3::new(810.0, 420.0, 1.0), epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_unproject() {
+    let (view, proj, viewport) = camera();
+    let point = Point3::new(1.5, -2.0, -3.0);
+    let window = project(point, view, proj, viewport);
+    assert_relative_eq!(unproject(window, view, proj, viewport).unwrap(), point, epsilon = 1.0e-9);
+
+    let d3d = Convention::direct3d();
+    let (view, proj) = (d3d.look_at(Point3::new(0.0, 0.0, 5.0), Point3::new(0.0, 0.0, 0.0), Vector3::unit_y()),
+                        d3d.perspective(Deg(90.0), 2.0, 1.0, 100.0));
+    let window = d3d.project(point, view, proj, viewport);
+    assert_relative_eq!(d3d.unproject(window, view, proj, viewport).unwrap(), point, epsilon = 1.0e-9);
+
+    assert_eq!(unproject(window, view, Matrix4::from_scale(0.0), viewport), None);
+}
+
+#[test]
+fn test_pick_ray() {
+    let (view, proj, viewport) = camera();
+
+    let ray = pick_ray(Point2::new(400.0, 200.0), view, proj, viewport).unwrap();
+    assert_relative_eq!(ray.origin, Point3::new(0.0, 0.0, 4.0), epsilon = 1.0e-9);
+    assert_relative_eq!(ray.direction, -Vector3::unit_z(), epsilon = 1.0e-9);
+
+    // The top left corner of the viewport
+    let ray = pick_ray(Point2::new(0.0, 0.0), view, proj, viewport).unwrap();
+    assert_relative_eq!(ray.origin, Point3::new(-2.0, 1.0, 4.0), epsilon = 1.0e-9);
+    assert_relative_eq!(ray.direction, Vector3::new(-2.0, 1.0, -1.0).normalize(), epsilon = 1.0e-9);
+
+    // Vulkan flips the `y` axis in both the projection and the window
+    let vk = Convention::vulkan();
+    let proj = vk.perspective(Deg(90.0), 2.0, 1.0, 100.0);
+    let vk_ray = vk.pick_ray(Point2::new(0.0, 0.0), view, proj, viewport).unwrap();
+    assert_relative_eq!(vk_ray, ray, epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_pick_ray_projections() {
+    let (view, _, viewport) = camera();
+    let (gl, reverse_z) = (Convention::opengl(), Convention::opengl().reverse_z());
+    let cameras = [(gl, perspective(Deg(90.0), 2.0, 1.0, 100.0)),
+                   (gl, perspective_infinite(Deg(90.0), 2.0, 1.0)),
+                   (reverse_z, perspective_reverse_z(Deg(90.0), 2.0, 1.0, 100.0)),
+                   (reverse_z, perspective_reverse_z_infinite(Deg(90.0), 2.0, 1.0))];
+
+    for &(convention, proj) in cameras.iter() {
+        let ray = convention.pick_ray(Point2::new(400.0, 200.0), view, proj, viewport).unwrap();
+        assert_relative_eq!(ray.origin, Point3::new(0.0, 0.0, 4.0), epsilon = 1.0e-9);
+        assert_relative_eq!(ray.direction, -Vector3::unit_z(), epsilon = 1.0e-9);
+
+        let ray = convention.pick_ray(Point2::new(0.0, 0.0), view, proj, viewport).unwrap();
+        assert_relative_eq!(ray.origin, Point3::new(-2.0, 1.0, 4.0), epsilon = 1.0e-9);
+        assert_relative_eq!(ray.direction, Vector3::new(-2.0, 1.0, -1.0).normalize(), epsilon = 1.0e-9);
+    }
+
+    assert_eq!(pick_ray(Point2::new(400.0, 200.0), view, Matrix4::from_scale(0.0), viewport), None);
+}
@@ -341,3 +341,245 @@ mod cast {
                         Quaternion::new(0.9f32, 1.5, 2.4, 7.6));
     }
 }
+
+mod exp_ln {
+    use cgmath::*;
+
+    #[test]
+    fn test_exp_of_pure_quaternion() {
+        let axis = vec3(1.0f64, -2.0, 0.5).normalize();
+        let q = Quaternion::from_sv(0.0, axis * 0.6).exp();
+        assert_relative_eq!(q, Quaternion::from_axis_angle(axis, Rad(1.2)), epsilon = 1.0e-12);
+        assert_eq!(Quaternion::<f64>::zero().exp(), Quaternion::one());
+    }
+
+    #[test]
+    fn test_ln_inverts_exp() {
+        let q = Quaternion::new(0.5f64, -1.0, 2.0, 0.25);
+        assert_relative_eq!(q.ln().exp(), q, epsilon = 1.0e-12);
+        assert_relative_eq!(q.exp().ln(), q, epsilon = 1.0e-12);
+        assert_relative_eq!(Quaternion::new(2.0f64, 0.0, 0.0, 0.0).ln(),
+                            Quaternion::new(2.0f64.ln(), 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_powf() {
+        let axis = vec3(0.0f64, 1.0, 1.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Rad(0.9));
+        assert_relative_eq!(q.powf(2.0), q * q, epsilon = 1.0e-12);
+        assert_relative_eq!(q.powf(-1.0), q.invert(), epsilon = 1.0e-12);
+        assert_relative_eq!(q.powf(0.5), Quaternion::from_axis_angle(axis, Rad(0.45)), epsilon = 1.0e-12);
+        assert_relative_eq!(q.powf(0.3), Quaternion::one().slerp(q, 0.3), epsilon = 1.0e-12);
+    }
+}
+
+mod squad {
+    use cgmath::*;
+
+    fn keys() -> [Quaternion<f64>; 4] {
+        [Quaternion::one(),
+         Quaternion::from_axis_angle(Vector3::unit_z(), Rad(1.0)),
+         Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Rad(2.0)),
+         Quaternion::from_axis_angle(Vector3::unit_x(), Rad(-0.5))]
+    }
+
+    fn segment(keys: &[Quaternion<f64>; 4], i: usize, amount: f64) -> Quaternion<f64> {
+        let tangent = |i: usize| keys[i].squad_tangent(keys[i.saturating_sub(1)], keys[(i + 1).min(3)]);
+        keys[i].squad(tangent(i), tangent(i + 1), keys[i + 1], amount)
+    }
+
+    #[test]
+    fn test_interpolates_keys() {
+        let keys = keys();
+        for i in 0..3 {
+            assert_relative_eq!(segment(&keys, i, 0.0), keys[i], epsilon = 1.0e-12);
+            assert_relative_eq!(segment(&keys, i, 1.0), keys[i + 1], epsilon = 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn test_tangent_of_endpoint() {
+        let q = Quaternion::from_axis_angle(Vector3::unit_y(), Rad(0.7f64));
+        assert_relative_eq!(q.squad_tangent(q, q), q, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_continuous_angular_velocity() {
+        let keys = keys();
+        let h = 1.0e-6;
+        for i in 0..2 {
+            let before = (segment(&keys, i, 1.0) - segment(&keys, i, 1.0 - h)) / h;
+            let after = (segment(&keys, i + 1, h) - segment(&keys, i + 1, 0.0)) / h;
+            assert_relative_eq!(before, after, epsilon = 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn test_velocity_kink_without_tangents() {
+        // Piecewise slerp changes velocity abruptly at each keyframe
+        let keys = keys();
+        let h = 1.0e-6;
+        let before = (keys[0].slerp(keys[1], 1.0) - keys[0].slerp(keys[1], 1.0 - h)) / h;
+        let after = (keys[1].slerp(keys[2], h) - keys[1].slerp(keys[2], 0.0)) / h;
+        assert!(relative_ne!(before, after, epsilon = 1.0e-2));
+    }
+}
+
+mod swing_twist {
+    use cgmath::*;
+
+    #[test]
+    fn test_decomposition() {
+        let axis = vec3(0.0f64, 1.0, 0.0);
+        let q = Quaternion::from_axis_angle(vec3(1.0, 2.0, -1.0).normalize(), Rad(1.3));
+        let (swing, twist) = q.swing_twist(axis);
+
+        assert_relative_eq!(swing * twist, q, epsilon = 1.0e-12);
+        assert_relative_eq!(twist.v.cross(axis), Vector3::zero(), epsilon = 1.0e-12);
+        assert_relative_eq!(swing.v.dot(axis), 0.0, epsilon = 1.0e-12);
+        assert_relative_eq!(twist.magnitude(), 1.0, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_pure_rotations() {
+        let axis = vec3(0.0f64, 0.0, 1.0);
+        let twist = Quaternion::from_axis_angle(axis, Rad(0.8));
+        let swing = Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Rad(0.4));
+
+        let (s, t) = twist.swing_twist(axis);
+        assert_relative_eq!(s, Quaternion::one(), epsilon = 1.0e-12);
+        assert_relative_eq!(t, twist, epsilon = 1.0e-12);
+
+        let (s, t) = (swing * twist).swing_twist(axis);
+        assert_relative_eq!(s, swing, epsilon = 1.0e-12);
+        assert_relative_eq!(t, twist, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_degenerate_twist() {
+        let axis = vec3(0.0f64, 0.0, 1.0);
+        let q = Quaternion::from_axis_angle(vec3(1.0, 0.0, 0.0), Rad::turn_div_2());
+        let (swing, twist) = q.swing_twist(axis);
+        assert_eq!(twist, Quaternion::one());
+        assert_eq!(swing, q);
+    }
+
+    #[test]
+    fn test_twist_angle() {
+        let up = vec3(0.0f64, 1.0, 0.0);
+        let yaw = Quaternion::from_axis_angle(up, Rad(-2.5));
+        let pitch = Quaternion::from_axis_angle(vec3(1.0, 0.0, 0.0), Rad(0.6));
+        assert_relative_eq!((yaw * pitch).twist_angle(up), Rad(-2.5), epsilon = 1.0e-12);
+        assert_relative_eq!((-yaw).twist_angle(up), Rad(-2.5), epsilon = 1.0e-12);
+    }
+}
+
+mod mean {
+    use cgmath::*;
+
+    fn rotation(x: f64, y: f64, z: f64) -> Quaternion<f64> {
+        Quaternion::from(Euler::new(Rad(x), Rad(y), Rad(z)))
+    }
+
+    #[test]
+    fn test_single() {
+        let q = rotation(0.3, -1.2, 2.0);
+        let mean = Quaternion::mean(&[q]).unwrap();
+        assert_relative_eq!(mean, if q.s < 0.0 { -q } else { q }, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_symmetric_about_axis() {
+        // Rotations spread evenly around a common rotation average to it
+        let center = rotation(0.5, 0.2, -0.4);
+        let axis = vec3(1.0, 1.0, 0.0).normalize();
+        let offsets = [Quaternion::from_axis_angle(axis, Rad(0.3)),
+                       Quaternion::from_axis_angle(axis, Rad(-0.3))];
+        let rotations: Vec<_> = offsets.iter().map(|&o| center * o).collect();
+        assert_relative_eq!(Quaternion::mean(&rotations).unwrap(), center, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_sign_and_order_invariant() {
+        let (a, b, c) = (rotation(0.1, 0.2, 0.3), rotation(-0.4, 0.1, 0.2), rotation(0.2, -0.3, 0.5));
+        let mean = Quaternion::mean(&[a, b, c]).unwrap();
+        assert_relative_eq!(Quaternion::mean(&[c, -a, b]).unwrap(), mean, epsilon = 1.0e-12);
+        assert!(mean.s >= 0.0);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let a = rotation(0.0, 0.0, 0.0);
+        let b = Quaternion::from_angle_z(Rad(1.0));
+        let mean = Quaternion::weighted_mean(&[a, b], &[1.0, 3.0]).unwrap();
+        let (axis, angle) = mean.to_axis_angle();
+        assert_relative_eq!(axis, Vector3::unit_z(), epsilon = 1.0e-12);
+        assert!(angle > Rad(0.5) && angle < Rad(1.0));
+
+        assert_relative_eq!(Quaternion::weighted_mean(&[a, b], &[0.0, 1.0]).unwrap(), b, epsilon = 1.0e-12);
+
+        // Only the relative size of the weights matters
+        let small = Quaternion::weighted_mean(&[a, b], &[1.0e-30, 3.0e-30]).unwrap();
+        assert_relative_eq!(small, mean, epsilon = 1.0e-12);
+        let small = Quaternion::weighted_mean(&[a.cast().unwrap(), b.cast().unwrap()], &[1.0e-9f32, 3.0e-9]).unwrap();
+        assert_relative_eq!(small, mean.cast().unwrap(), epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn test_basis3() {
+        let rotations = [rotation(0.1, 0.2, 0.3), rotation(-0.4, 0.1, 0.2), rotation(0.2, -0.3, 0.5)];
+        let bases: Vec<_> = rotations.iter().map(Basis3::from_quaternion).collect();
+        let mean = Basis3::mean(&bases).unwrap();
+        let expected = Basis3::from_quaternion(&Quaternion::mean(&rotations).unwrap());
+        assert_relative_eq!(mean, expected, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert!(Quaternion::<f64>::mean(&[]).is_none());
+        assert!(Basis3::<f64>::mean(&[]).is_none());
+        let q = rotation(0.1, 0.2, 0.3);
+        assert!(Quaternion::weighted_mean(&[q, q], &[1.0, -1.0]).is_none());
+    }
+}
+
+mod angular_velocity {
+    use cgmath::*;
+
+    #[test]
+    fn test_integrate() {
+        let q = Quaternion::from(Euler::new(Rad(0.3f64), Rad(-0.2), Rad(1.1)));
+        let axis = vec3(1.0, 2.0, 2.0) / 3.0;
+        let omega = axis * 4.0;
+
+        // Rotating at 4 rad/s for 0.5 s is a 2 rad turn about the axis
+        let expected = Quaternion::from_axis_angle(axis, Rad(2.0)) * q;
+        assert_relative_eq!(q.integrate(omega, 0.5), expected, epsilon = 1.0e-12);
+
+        // Many small steps give the same result as one large one
+        let stepped = (0..100).fold(q, |q, _| q.integrate(omega, 0.005));
+        assert_relative_eq!(stepped, expected, epsilon = 1.0e-12);
+        assert_relative_eq!(stepped.magnitude(), 1.0, epsilon = 1.0e-12);
+
+        assert_relative_eq!(q.integrate(Vector3::zero(), 1.0), q);
+    }
+
+    #[test]
+    fn test_derivative() {
+        let q = Quaternion::from(Euler::new(Rad(0.3f64), Rad(-0.2), Rad(1.1)));
+        let omega = vec3(0.5, -1.0, 2.0);
+        let h = 1.0e-7;
+        let numeric = (q.integrate(omega, h) - q) / h;
+        assert_relative_eq!(q.derivative(omega), numeric, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn test_angular_velocity() {
+        let from = Quaternion::from(Euler::new(Rad(0.3f64), Rad(-0.2), Rad(1.1)));
+        let omega = vec3(0.5, -1.0, 2.0);
+        let to = from.integrate(omega, 0.25);
+        assert_relative_eq!(Quaternion::angular_velocity(from, to, 0.25), omega, epsilon = 1.0e-12);
+        assert_relative_eq!(Quaternion::angular_velocity(from, -to, 0.25), omega, epsilon = 1.0e-12);
+        assert_relative_eq!(Quaternion::angular_velocity(from, from, 0.25), Vector3::zero());
+    }
+}
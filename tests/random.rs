@@ -0,0 +1,152 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "rand")]
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+extern crate rand;
+
+use cgmath::*;
+use rand::{Rng, SeedableRng, XorShiftRng};
+use rand::distributions::{IndependentSample, Sample};
+
+const SAMPLES: usize = 20000;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([0x193a_6754, 0xa8a7_d469, 0x9783_0e05, 0x113b_a7bb])
+}
+
+fn samples<T, D: IndependentSample<T>>(dist: D) -> Vec<T> {
+    let mut rng = rng();
+    (0..SAMPLES).map(|_| dist.ind_sample(&mut rng)).collect()
+}
+
+fn mean<I: Iterator<Item = f64>>(iter: I) -> f64 {
+    iter.sum::<f64>() / SAMPLES as f64
+}
+
+#[test]
+fn test_uniform_rotation() {
+    let qs: Vec<Quaternion<f64>> = samples(UniformRotation);
+    for q in &qs {
+        assert_relative_eq!(q.magnitude(), 1.0, epsilon = 1.0e-12);
+    }
+
+    // Every component of a uniformly distributed unit quaternion has the same
+    // variance
+    assert_relative_eq!(mean(qs.iter().map(|q| q.s * q.s)), 0.25, epsilon = 0.01);
+    assert_relative_eq!(mean(qs.iter().map(|q| q.v.x * q.v.x)), 0.25, epsilon = 0.01);
+    assert_relative_eq!(mean(qs.iter().map(|q| q.v.y * q.v.y)), 0.25, epsilon = 0.01);
+    assert_relative_eq!(mean(qs.iter().map(|q| q.v.z * q.v.z)), 0.25, epsilon = 0.01);
+
+    // Rotated vectors have no preferred direction
+    let v = Vector3::unit_x();
+    assert_relative_eq!(mean(qs.iter().map(|q| q.rotate_vector(v).x)), 0.0, epsilon = 0.02);
+    assert_relative_eq!(mean(qs.iter().map(|q| q.rotate_vector(v).y)), 0.0, epsilon = 0.02);
+}
+
+#[test]
+fn test_uniform_rotation_basis3() {
+    let mut rng = rng();
+    let a: Basis3<f64> = UniformRotation.sample(&mut rng);
+    let b: Basis3<f64> = rng.gen();
+    for m in [a, b].iter() {
+        let m: &Matrix3<f64> = m.as_ref();
+        assert_relative_eq!(m * m.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(m.determinant(), 1.0, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_unit_circle() {
+    let vs: Vec<Vector2<f64>> = samples(UnitCircle);
+    for v in &vs {
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1.0e-12);
+    }
+    assert_relative_eq!(mean(vs.iter().map(|v| v.x)), 0.0, epsilon = 0.02);
+    assert_relative_eq!(mean(vs.iter().map(|v| v.y)), 0.0, epsilon = 0.02);
+}
+
+#[test]
+fn test_unit_sphere() {
+    let vs: Vec<Vector3<f64>> = samples(UnitSphere);
+    for v in &vs {
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1.0e-12);
+    }
+    assert_relative_eq!(mean(vs.iter().map(|v| v.x)), 0.0, epsilon = 0.02);
+    assert_relative_eq!(mean(vs.iter().map(|v| v.z)), 0.0, epsilon = 0.02);
+    assert_relative_eq!(mean(vs.iter().map(|v| v.x * v.x)), 1.0 / 3.0, epsilon = 0.01);
+    assert_relative_eq!(mean(vs.iter().map(|v| v.z * v.z)), 1.0 / 3.0, epsilon = 0.01);
+}
+
+#[test]
+fn test_unit_disk() {
+    let vs: Vec<Vector2<f64>> = samples(UnitDisk);
+    assert!(vs.iter().all(|v| v.magnitude2() <= 1.0));
+    assert_relative_eq!(mean(vs.iter().map(|v| v.magnitude2())), 0.5, epsilon = 0.01);
+}
+
+#[test]
+fn test_unit_ball() {
+    let vs: Vec<Vector3<f64>> = samples(UnitBall);
+    assert!(vs.iter().all(|v| v.magnitude2() <= 1.0));
+    assert_relative_eq!(mean(vs.iter().map(|v| v.magnitude2())), 0.6, epsilon = 0.01);
+}
+
+#[test]
+fn test_cosine_hemisphere() {
+    let normal = vec3(1.0, -2.0, 0.5).normalize();
+    let vs: Vec<Vector3<f64>> = samples(CosineHemisphere::new(normal));
+    for v in &vs {
+        assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1.0e-12);
+        assert!(v.dot(normal) >= 0.0);
+    }
+    assert_relative_eq!(mean(vs.iter().map(|v| v.dot(normal))), 2.0 / 3.0, epsilon = 0.01);
+}
+
+#[test]
+fn test_triangle() {
+    let (a, b, c) = (Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), Point3::new(0.0, 0.0, 3.0));
+    let ps: Vec<Point3<f64>> = samples(Triangle::new(a, b, c));
+    let normal = (b - a).cross(c - a);
+    for p in &ps {
+        // Inside the triangle, all of the sub-triangles have the same winding
+        assert_relative_eq!(normal.dot(p - a), 0.0, epsilon = 1.0e-12);
+        assert!((b - a).cross(p - a).dot(normal) >= 0.0);
+        assert!((c - b).cross(p - b).dot(normal) >= 0.0);
+        assert!((a - c).cross(p - c).dot(normal) >= 0.0);
+    }
+    let centroid = Point3::centroid(&[a, b, c]);
+    assert_relative_eq!(mean(ps.iter().map(|p| p.x)), centroid.x, epsilon = 0.02);
+    assert_relative_eq!(mean(ps.iter().map(|p| p.z)), centroid.z, epsilon = 0.02);
+
+    let p: Point2<f64> = Triangle::new(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 1.0))
+        .ind_sample(&mut rng());
+    assert!(p.x >= 0.0 && p.y >= 0.0 && p.x + p.y <= 1.0);
+}
+
+#[test]
+fn test_aabb() {
+    let aabb = Aabb3::new(Point3::new(-1.0, 2.0, 0.0), Point3::new(3.0, 4.0, 0.5));
+    let ps: Vec<Point3<f64>> = samples(aabb);
+    assert!(ps.iter().all(|&p| aabb.contains_point(p)));
+    assert_relative_eq!(mean(ps.iter().map(|p| p.x)), 1.0, epsilon = 0.05);
+    assert_relative_eq!(mean(ps.iter().map(|p| p.y)), 3.0, epsilon = 0.05);
+
+    let aabb = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0));
+    assert!(aabb.contains_point(aabb.ind_sample(&mut rng())));
+}
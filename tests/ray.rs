@@ -0,0 +1,174 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn ray_z() -> Ray3<f64> {
+    Ray3::new(Point3::new(0.0, 0.0, -5.0), Vector3::unit_z())
+}
+
+mod plane {
+    use cgmath::*;
+
+    #[test]
+    fn test_from_points() {
+        let plane = Plane::from_points(Point3::new(0.0f64, 0.0, 2.0),
+                                       Point3::new(1.0, 0.0, 2.0),
+                                       Point3::new(0.0, 1.0, 2.0)).unwrap();
+        assert_ulps_eq!(plane, Plane::new(Vector3::unit_z(), 2.0));
+        assert_ulps_eq!(plane.signed_distance(Point3::new(5.0, -3.0, 5.0)), 3.0);
+        assert_ulps_eq!(plane.signed_distance(Point3::new(5.0, -3.0, 0.0)), -2.0);
+    }
+
+    #[test]
+    fn test_from_collinear_points() {
+        let plane = Plane::from_points(Point3::new(0.0f64, 0.0, 0.0),
+                                       Point3::new(1.0, 1.0, 1.0),
+                                       Point3::new(2.0, 2.0, 2.0));
+        assert_eq!(plane, None);
+    }
+
+    #[test]
+    fn test_from_small_points() {
+        // A millimetre sized triangle, in metres
+        let plane = Plane::from_points(Point3::new(0.0f32, 0.0, 0.002),
+                                       Point3::new(0.001, 0.0, 0.002),
+                                       Point3::new(0.0, 0.001, 0.002)).unwrap();
+        assert_ulps_eq!(plane, Plane::new(Vector3::unit_z(), 0.002));
+
+        let collinear = Plane::from_points(Point3::new(0.0f32, 0.0, 0.0),
+                                           Point3::new(0.001, 0.001, 0.001),
+                                           Point3::new(0.002, 0.002, 0.002));
+        assert_eq!(collinear, None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let plane = Plane::new(Vector3::new(0.0f64, 3.0, 4.0), 10.0).normalize();
+        assert_ulps_eq!(plane, Plane::new(Vector3::new(0.0, 0.6, 0.8), 2.0));
+    }
+}
+
+#[test]
+fn test_point_at() {
+    assert_ulps_eq!(ray_z().point_at(2.0), Point3::new(0.0, 0.0, -3.0));
+}
+
+#[test]
+fn test_intersect_plane() {
+    let plane = Plane::from_point_normal(Point3::new(0.0, 0.0, 1.0), Vector3::unit_z());
+    let (t, p) = ray_z().intersect_plane(&plane).unwrap();
+    assert_ulps_eq!(t, 6.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, 1.0));
+
+    // Facing away from the plane
+    let away = Ray3::new(Point3::new(0.0, 0.0, -5.0), -Vector3::unit_z());
+    assert_eq!(away.intersect_plane(&plane), None);
+
+    // Parallel to the plane
+    let parallel = Ray3::new(Point3::new(0.0, 0.0, -5.0), Vector3::unit_x());
+    assert_eq!(parallel.intersect_plane(&plane), None);
+
+    // Grazing, with a short direction vector
+    let plane = Plane::from_point_normal(Point3::new(0.0f32, 0.0, 1.0), Vector3::unit_z());
+    let grazing = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0e-4, 0.0, 1.0e-7));
+    let (t, p) = grazing.intersect_plane(&plane).unwrap();
+    assert_relative_eq!(t, 1.0e7);
+    assert_relative_eq!(p, Point3::new(1000.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_intersect_sphere() {
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    let (t, p) = ray_z().intersect_sphere(&sphere).unwrap();
+    assert_ulps_eq!(t, 4.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, -1.0));
+
+    // Starting inside the sphere reports the exit point
+    let inside = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::unit_x());
+    let (t, p) = inside.intersect_sphere(&sphere).unwrap();
+    assert_ulps_eq!(t, 1.0);
+    assert_ulps_eq!(p, Point3::new(1.0, 0.0, 0.0));
+
+    let miss = Ray3::new(Point3::new(2.0, 0.0, -5.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_sphere(&sphere), None);
+
+    let behind = Ray3::new(Point3::new(0.0, 0.0, 5.0), Vector3::unit_z());
+    assert_eq!(behind.intersect_sphere(&sphere), None);
+}
+
+#[test]
+fn test_intersect_triangle() {
+    let a = Point3::new(-1.0, -1.0, 0.0);
+    let b = Point3::new(1.0, -1.0, 0.0);
+    let c = Point3::new(0.0, 1.0, 0.0);
+
+    let (t, p) = ray_z().intersect_triangle(a, b, c).unwrap();
+    assert_ulps_eq!(t, 5.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, 0.0));
+
+    // The winding of the triangle does not matter
+    assert_eq!(ray_z().intersect_triangle(a, c, b), ray_z().intersect_triangle(a, b, c));
+
+    let miss = Ray3::new(Point3::new(0.9, 0.9, -5.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_triangle(a, b, c), None);
+
+    let parallel = Ray3::new(Point3::new(0.0, 0.0, -5.0), Vector3::unit_x());
+    assert_eq!(parallel.intersect_triangle(a, b, c), None);
+}
+
+#[test]
+fn test_intersect_small_triangle() {
+    let a = Point3::new(-0.001f32, -0.001, 0.0);
+    let b = Point3::new(0.001, -0.001, 0.0);
+    let c = Point3::new(0.0, 0.001, 0.0);
+
+    let ray = Ray3::new(Point3::new(0.0, 0.0, -0.005), Vector3::new(0.0, 0.0, 0.01));
+    let (t, p) = ray.intersect_triangle(a, b, c).unwrap();
+    assert_ulps_eq!(t, 0.5);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, 0.0));
+
+    let parallel = Ray3::new(Point3::new(0.0, 0.0, -0.005), Vector3::new(0.01, 0.0, 0.0));
+    assert_eq!(parallel.intersect_triangle(a, b, c), None);
+}
+
+#[test]
+fn test_intersect_aabb() {
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    let (t, p) = ray_z().intersect_aabb(&aabb).unwrap();
+    assert_ulps_eq!(t, 4.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 0.0, -1.0));
+
+    let diagonal = Ray3::new(Point3::new(-3.0, -3.0, -3.0), Vector3::new(1.0, 1.0, 1.0));
+    let (t, p) = diagonal.intersect_aabb(&aabb).unwrap();
+    assert_ulps_eq!(t, 2.0);
+    assert_ulps_eq!(p, Point3::new(-1.0, -1.0, -1.0));
+
+    // Starting inside the box reports the exit point
+    let inside = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::unit_y());
+    let (t, p) = inside.intersect_aabb(&aabb).unwrap();
+    assert_ulps_eq!(t, 1.0);
+    assert_ulps_eq!(p, Point3::new(0.0, 1.0, 0.0));
+
+    let miss = Ray3::new(Point3::new(2.0, 0.0, -5.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_aabb(&aabb), None);
+
+    let behind = Ray3::new(Point3::new(0.0, 0.0, 5.0), Vector3::unit_z());
+    assert_eq!(behind.intersect_aabb(&aabb), None);
+}
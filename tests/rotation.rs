@@ -13,9 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+extern crate approx;
 extern crate cgmath;
 
 use cgmath::*;
+use std::f64::consts::PI;
+use std::fmt::Debug;
 
 mod rotation {
     use super::cgmath::*;
@@ -45,3 +49,44 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+fn check_axis_angle<R: Rotation3<f64> + RelativeEq<Epsilon = f64> + Debug>() {
+    let axis = Vector3::new(1.0, -2.0, 2.0).normalize();
+    for &angle in [0.3, 1.5, 3.0, -2.0].iter() {
+        let r: R = Rotation3::from_axis_angle(axis, Rad(angle));
+        let (a, theta) = r.to_axis_angle();
+        assert_relative_eq!(a, axis * angle.signum(), epsilon = 1.0e-12);
+        assert_relative_eq!(theta, Rad(angle.abs()), epsilon = 1.0e-12);
+        assert_relative_eq!(R::from_axis_angle(a, theta), r, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_to_axis_angle_quaternion() {
+    check_axis_angle::<Quaternion<f64>>();
+}
+
+#[test]
+fn test_to_axis_angle_basis3() {
+    check_axis_angle::<Basis3<f64>>();
+}
+
+#[test]
+fn test_to_axis_angle_matrix3() {
+    let axis = Vector3::new(0.0, 3.0, 4.0).normalize();
+    let (a, theta) = Matrix3::from_axis_angle(axis, Rad(2.5)).to_axis_angle();
+    assert_relative_eq!(a, axis, epsilon = 1.0e-12);
+    assert_relative_eq!(theta, Rad(2.5), epsilon = 1.0e-12);
+
+    // A half turn has no preferred axis direction
+    let (a, theta) = Matrix3::from_angle_z(Rad(PI)).to_axis_angle();
+    assert_relative_eq!(a.z.abs(), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(theta, Rad(PI), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_to_axis_angle_identity() {
+    let (a, theta) = Quaternion::<f64>::one().to_axis_angle();
+    assert_eq!(a, Vector3::unit_x());
+    assert_eq!(theta, Rad(0.0));
+}
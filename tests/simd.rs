@@ -0,0 +1,263 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The operations below have SIMD fast paths when the `simd` feature is
+//! enabled. These tests compare them bit for bit against the scalar formulas,
+//! and run with and without the feature.
+
+extern crate cgmath;
+extern crate rand;
+
+use cgmath::*;
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+const ITERATIONS: usize = 1000;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([0x193a_6754, 0xa8a7_d469, 0x9783_0e05, 0x113b_a7bb])
+}
+
+/// A random value of varying magnitude, which is sometimes a signed zero.
+fn scalar<R: Rng>(rng: &mut R) -> f32 {
+    match rng.gen_range(0, 8) {
+        0 => 0.0,
+        1 => -0.0,
+        _ => (rng.gen::<f32>() - 0.5) * 2f32.powi(rng.gen_range(-20, 20)),
+    }
+}
+
+fn vector4<R: Rng>(rng: &mut R) -> Vector4<f32> {
+    Vector4::new(scalar(rng), scalar(rng), scalar(rng), scalar(rng))
+}
+
+fn matrix4<R: Rng>(rng: &mut R) -> Matrix4<f32> {
+    Matrix4::from_cols(vector4(rng), vector4(rng), vector4(rng), vector4(rng))
+}
+
+fn quaternion<R: Rng>(rng: &mut R) -> Quaternion<f32> {
+    Quaternion::new(scalar(rng), scalar(rng), scalar(rng), scalar(rng))
+}
+
+fn bits(v: Vector4<f32>) -> [u32; 4] {
+    [v.x.to_bits(), v.y.to_bits(), v.z.to_bits(), v.w.to_bits()]
+}
+
+fn row_dot(m: Matrix4<f32>, i: usize, v: Vector4<f32>) -> f32 {
+    m.x[i] * v.x + m.y[i] * v.y + m.z[i] * v.z + m.w[i] * v.w
+}
+
+fn scalar_mul_matrix4_vector4(m: Matrix4<f32>, v: Vector4<f32>) -> Vector4<f32> {
+    Vector4::new(row_dot(m, 0, v), row_dot(m, 1, v), row_dot(m, 2, v), row_dot(m, 3, v))
+}
+
+#[test]
+fn test_mul_matrix4_vector4() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (m, v) = (matrix4(&mut rng), vector4(&mut rng));
+        let expected = scalar_mul_matrix4_vector4(m, v);
+        assert_eq!(bits(m * v), bits(expected));
+    }
+}
+
+#[test]
+fn test_mul_matrix4() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b) = (matrix4(&mut rng), matrix4(&mut rng));
+        let product = a * b;
+        for (column, &rhs) in [b.x, b.y, b.z, b.w].iter().enumerate() {
+            assert_eq!(bits(product[column]), bits(scalar_mul_matrix4_vector4(a, rhs)));
+        }
+    }
+}
+
+#[test]
+fn test_mul_quaternion() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b) = (quaternion(&mut rng), quaternion(&mut rng));
+        let expected = Vector4::new(a.s * b.s - a.v.x * b.v.x - a.v.y * b.v.y - a.v.z * b.v.z,
+                                    a.s * b.v.x + a.v.x * b.s + a.v.y * b.v.z - a.v.z * b.v.y,
+                                    a.s * b.v.y + a.v.y * b.s + a.v.z * b.v.x - a.v.x * b.v.z,
+                                    a.s * b.v.z + a.v.z * b.s + a.v.x * b.v.y - a.v.y * b.v.x);
+        let product = a * b;
+        assert_eq!(bits(Vector4::new(product.s, product.v.x, product.v.y, product.v.z)), bits(expected));
+    }
+}
+
+fn quaternion_bits(q: Quaternion<f32>) -> [u32; 4] {
+    bits(Vector4::new(q.s, q.v.x, q.v.y, q.v.z))
+}
+
+/// Applies `op` to each component, like the scalar implementation.
+fn map2(a: Vector4<f32>, b: Vector4<f32>, op: fn(f32, f32) -> f32) -> Vector4<f32> {
+    Vector4::new(op(a.x, b.x), op(a.y, b.y), op(a.z, b.z), op(a.w, b.w))
+}
+
+#[test]
+fn test_vector4_arithmetic() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b, s) = (vector4(&mut rng), vector4(&mut rng), scalar(&mut rng));
+        let splat = Vector4::from_value(s);
+
+        assert_eq!(bits(a + b), bits(map2(a, b, |x, y| x + y)));
+        assert_eq!(bits(a - b), bits(map2(a, b, |x, y| x - y)));
+        assert_eq!(bits(a * s), bits(map2(a, splat, |x, y| x * y)));
+        assert_eq!(bits(a / s), bits(map2(a, splat, |x, y| x / y)));
+        assert_eq!(bits(s * a), bits(map2(splat, a, |x, y| x * y)));
+        assert_eq!(bits(s / a), bits(map2(splat, a, |x, y| x / y)));
+        assert_eq!(bits(-a), bits(Vector4::new(-a.x, -a.y, -a.z, -a.w)));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(bits(c), bits(a + b));
+        c = a;
+        c -= b;
+        assert_eq!(bits(c), bits(a - b));
+        c = a;
+        c *= s;
+        assert_eq!(bits(c), bits(a * s));
+        c = a;
+        c /= s;
+        assert_eq!(bits(c), bits(a / s));
+    }
+}
+
+#[test]
+fn test_vector4_element_wise() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b, s) = (vector4(&mut rng), vector4(&mut rng), scalar(&mut rng));
+        let splat = Vector4::from_value(s);
+
+        assert_eq!(bits(a.mul_element_wise(b)), bits(map2(a, b, |x, y| x * y)));
+        assert_eq!(bits(a.div_element_wise(b)), bits(map2(a, b, |x, y| x / y)));
+        assert_eq!(bits(a.add_element_wise(s)), bits(map2(a, splat, |x, y| x + y)));
+        assert_eq!(bits(a.sub_element_wise(s)), bits(map2(a, splat, |x, y| x - y)));
+
+        let mut c = a;
+        c.mul_assign_element_wise(b);
+        assert_eq!(bits(c), bits(a.mul_element_wise(b)));
+        c = a;
+        c.div_assign_element_wise(b);
+        assert_eq!(bits(c), bits(a.div_element_wise(b)));
+        c = a;
+        c.add_assign_element_wise(s);
+        assert_eq!(bits(c), bits(a.add_element_wise(s)));
+        c = a;
+        c.sub_assign_element_wise(s);
+        assert_eq!(bits(c), bits(a.sub_element_wise(s)));
+    }
+}
+
+#[test]
+fn test_vector4_dot() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b) = (vector4(&mut rng), vector4(&mut rng));
+        let expected = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+        assert_eq!(a.dot(b).to_bits(), expected.to_bits());
+        assert_eq!(a.magnitude2().to_bits(), a.dot(a).to_bits());
+    }
+}
+
+/// Applies `op` to each component, like the scalar implementation.
+fn component<F: Fn(f32) -> f32>(q: Quaternion<f32>, op: F) -> Quaternion<f32> {
+    Quaternion::new(op(q.s), op(q.v.x), op(q.v.y), op(q.v.z))
+}
+
+#[test]
+fn test_quaternion_arithmetic() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b, s) = (quaternion(&mut rng), quaternion(&mut rng), scalar(&mut rng));
+        let sum = Quaternion::new(a.s + b.s, a.v.x + b.v.x, a.v.y + b.v.y, a.v.z + b.v.z);
+        let difference = Quaternion::new(a.s - b.s, a.v.x - b.v.x, a.v.y - b.v.y, a.v.z - b.v.z);
+        assert_eq!(quaternion_bits(a + b), quaternion_bits(sum));
+        assert_eq!(quaternion_bits(a - b), quaternion_bits(difference));
+        assert_eq!(quaternion_bits(a * s), quaternion_bits(component(a, |x| x * s)));
+        assert_eq!(quaternion_bits(a / s), quaternion_bits(component(a, |x| x / s)));
+        assert_eq!(quaternion_bits(s * a), quaternion_bits(component(a, |x| s * x)));
+        assert_eq!(quaternion_bits(s / a), quaternion_bits(component(a, |x| s / x)));
+        assert_eq!(quaternion_bits(-a), quaternion_bits(component(a, |x| -x)));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(quaternion_bits(c), quaternion_bits(sum));
+        c = a;
+        c -= b;
+        assert_eq!(quaternion_bits(c), quaternion_bits(difference));
+        c = a;
+        c *= s;
+        assert_eq!(quaternion_bits(c), quaternion_bits(a * s));
+        c = a;
+        c /= s;
+        assert_eq!(quaternion_bits(c), quaternion_bits(a / s));
+    }
+}
+
+#[test]
+fn test_quaternion_dot() {
+    let mut rng = rng();
+    for _ in 0..ITERATIONS {
+        let (a, b) = (quaternion(&mut rng), quaternion(&mut rng));
+        let expected = a.s * b.s + (a.v.x * b.v.x + a.v.y * b.v.y + a.v.z * b.v.z);
+        assert_eq!(a.dot(b).to_bits(), expected.to_bits());
+    }
+}
+
+#[test]
+fn test_mul_f64_unaffected() {
+    let m = Matrix4::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0,
+                         9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    let v = Vector4::new(1.0f64, -1.0, 2.0, -2.0);
+    assert_eq!(m * v, Vector4::new(-12.0, -12.0, -12.0, -12.0));
+}
+
+#[test]
+fn test_batch_transforms() {
+    let mut rng = rng();
+    let m = Matrix4::from_translation(Vector3::new(1.0f32, -2.0, 3.0)) *
+            Matrix4::from_axis_angle(Vector3::new(1.0f32, 2.0, 2.0).normalize(), Deg(70.0)) *
+            perspective(Deg(60.0f32), 1.5, 0.1, 100.0);
+    let q = Quaternion::from_axis_angle(Vector3::new(0.0f32, 3.0, 4.0).normalize(), Deg(-40.0));
+    let src: Vec<_> = (0..ITERATIONS).map(|_| vector4(&mut rng).truncate()).collect();
+    let mut dst = vec![Vector3::zero(); src.len()];
+
+    m.transform_vectors(&src, &mut dst);
+    for (&v, &w) in src.iter().zip(&dst) {
+        assert_eq!(bits(w.extend(0.0)), bits(m.transform_vector(v).extend(0.0)));
+    }
+
+    q.rotate_vectors(&src, &mut dst);
+    for (&v, &w) in src.iter().zip(&dst) {
+        assert_eq!(bits(w.extend(0.0)), bits(q.rotate_vector(v).extend(0.0)));
+    }
+
+    let src: Vec<_> = src.iter().map(|&v| Point3::from_vec(v)).collect();
+    let mut dst = vec![Point3::origin(); src.len()];
+
+    m.transform_points(&src, &mut dst);
+    for (&p, &t) in src.iter().zip(&dst) {
+        assert_eq!(bits(t.to_homogeneous()), bits(m.transform_point(p).to_homogeneous()));
+    }
+
+    q.rotate_points(&src, &mut dst);
+    for (&p, &r) in src.iter().zip(&dst) {
+        assert_eq!(bits(r.to_homogeneous()), bits(q.rotate_point(p).to_homogeneous()));
+    }
+}
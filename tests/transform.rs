@@ -72,3 +72,157 @@ fn test_serialize() {
 
     assert_ulps_eq!(&t, &deserialized);
 }
+
+fn non_uniform() -> NonUniformDecomposed<Vector3<f64>, Quaternion<f64>> {
+    NonUniformDecomposed {
+        scale: Vector3::new(2.0f64, 0.5, -3.0),
+        rot: Quaternion::from(Euler::new(Deg(30.0f64), Deg(-45.0), Deg(110.0))),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    }
+}
+
+#[test]
+fn test_non_uniform_matrix() {
+    let t = non_uniform();
+    let m = Matrix4::from(t);
+    let p = Point3::new(1.0f64, 2.0, 3.0);
+    assert_relative_eq!(t.transform_point(p), m.transform_point(p), epsilon = 1.0e-12);
+    assert_relative_eq!(m, Matrix4::from_translation(t.disp) * Matrix4::from(t.rot) *
+                           Matrix4::from_nonuniform_scale(2.0, 0.5, -3.0), epsilon = 1.0e-12);
+
+    let d = Decomposed { scale: 1.5f64, rot: t.rot, disp: t.disp };
+    assert_relative_eq!(Matrix4::from(NonUniformDecomposed::from(d)), Matrix4::from(d), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_non_uniform_invert() {
+    let t = non_uniform();
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    let vt = t.inverse_transform_vector(v).unwrap();
+    assert_relative_eq!(t.transform_vector(vt), v, epsilon = 1.0e-12);
+
+    let ti = t.inverse_transform().unwrap();
+    assert_relative_eq!(ti.transform_point(Point3::from_vec(t.disp)), Point3::origin(), epsilon = 1.0e-12);
+    assert_relative_eq!(ti.concat(&t), NonUniformDecomposed::one(), epsilon = 1.0e-12);
+
+    let mut flat = t;
+    flat.scale.y = 0.0;
+    assert!(flat.inverse_transform().is_none());
+}
+
+#[test]
+fn test_non_uniform_concat() {
+    // Composition is exact when the child's rotation keeps the scaled axes aligned
+    let parent = non_uniform();
+    let child = NonUniformDecomposed {
+        scale: Vector3::new(1.0f64, 2.0, 4.0),
+        rot: Quaternion::from_angle_x(Deg(180.0)),
+        disp: Vector3::new(1.0f64, 1.0, -2.0),
+    };
+    let m = Matrix4::from(parent) * Matrix4::from(child);
+    assert_relative_eq!(Matrix4::from(parent.concat(&child)), m, epsilon = 1.0e-12);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_non_uniform() {
+    let t = non_uniform();
+    let serialized = serde_json::to_string(&t).unwrap();
+    let deserialized: NonUniformDecomposed<Vector3<f64>, Quaternion<f64>> = serde_json::from_str(&serialized).unwrap();
+    assert_ulps_eq!(&t, &deserialized);
+}
+
+fn batch_points() -> Vec<Point3<f64>> {
+    vec![Point3::new(1.0, 2.0, 3.0),
+         Point3::new(-4.0, 0.5, 2.0),
+         Point3::new(0.0, 0.0, -7.0),
+         Point3::new(3.0, -1.0, 0.25),
+         Point3::new(0.1, 0.2, 0.3)]
+}
+
+fn batch_transforms() -> (Matrix4<f64>, Decomposed<Vector3<f64>, Quaternion<f64>>) {
+    let m = Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0)) *
+            Matrix4::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalize(), Deg(70.0)) *
+            Matrix4::from_nonuniform_scale(2.0, 1.0, -0.5);
+    let d = Decomposed {
+        scale: -1.5f64,
+        rot: Quaternion::from_axis_angle(Vector3::new(0.0, 3.0, 4.0).normalize(), Deg(-40.0)),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    (m, d)
+}
+
+#[test]
+fn test_transform_points() {
+    let (m, d) = batch_transforms();
+    let src = batch_points();
+    let mut dst = vec![Point3::origin(); src.len()];
+
+    m.transform_points(&src, &mut dst);
+    for (&p, &q) in src.iter().zip(&dst) {
+        assert_eq!(q, m.transform_point(p));
+    }
+
+    d.transform_points(&src, &mut dst);
+    for (&p, &q) in src.iter().zip(&dst) {
+        assert_eq!(q, d.transform_point(p));
+    }
+
+    let mut points = src.clone();
+    m.transform_points_in_place(&mut points);
+    m.transform_points(&src, &mut dst);
+    assert_eq!(points, dst);
+}
+
+#[test]
+fn test_transform_vectors() {
+    let (m, d) = batch_transforms();
+    let src: Vec<_> = batch_points().iter().map(|p| p.to_vec()).collect();
+    let mut dst = vec![Vector3::zero(); src.len()];
+
+    m.transform_vectors(&src, &mut dst);
+    for (&v, &w) in src.iter().zip(&dst) {
+        assert_eq!(w, m.transform_vector(v));
+    }
+
+    let mut vectors = src.clone();
+    d.transform_vectors_in_place(&mut vectors);
+    for (&v, &w) in src.iter().zip(&vectors) {
+        assert_eq!(w, d.transform_vector(v));
+    }
+}
+
+#[test]
+fn test_transform_normals() {
+    let (m, d) = batch_transforms();
+    let src: Vec<_> = batch_points().iter().map(|p| p.to_vec()).collect();
+    let mut dst = vec![Vector3::zero(); src.len()];
+
+    // Both transforms contain a reflection, which the normals must follow
+    let linear = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+    let normal_matrix = linear.invert().unwrap().transpose();
+    m.transform_normals(&src, &mut dst);
+    for (&n, &t) in src.iter().zip(&dst) {
+        assert_relative_eq!(t, (normal_matrix * n).normalize(), epsilon = 1.0e-12);
+    }
+
+    let normal_matrix = (Matrix3::from(d.rot) * d.scale).invert().unwrap().transpose();
+    let mut normals = src.clone();
+    d.transform_normals_in_place(&mut normals);
+    for (&n, &t) in src.iter().zip(&normals) {
+        assert_relative_eq!(t, (normal_matrix * n).normalize(), epsilon = 1.0e-12);
+    }
+
+    d.rot.transform_normals(&src, &mut dst);
+    for (&n, &t) in src.iter().zip(&dst) {
+        assert_relative_eq!(t, d.rot.rotate_vector(n).normalize(), epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_transform_points_length_mismatch() {
+    let (m, _) = batch_transforms();
+    let mut dst = vec![Point3::origin(); 2];
+    m.transform_points(&batch_points(), &mut dst);
+}
@@ -261,3 +261,39 @@ fn test_cast() {
     assert_ulps_eq!(Vector3::new(1.0f64, 2.4, -3.13).cast().unwrap(), Vector3::new(1.0f32, 2.4, -3.13));
     assert_ulps_eq!(Vector4::new(13.5f64, -4.6, -8.3, 2.41).cast().unwrap(), Vector4::new(13.5f32, -4.6, -8.3, 2.41));
 }
+
+#[test]
+fn test_comparison_masks() {
+    let a = Vector4::new(1.0f64, 2.0, 3.0, f64::NAN);
+    let b = Vector4::new(2.0f64, 2.0, 1.0, 0.0);
+    assert_eq!(a.lt_element_wise(b), Vector4::new(true, false, false, false));
+    assert_eq!(a.le_element_wise(b), Vector4::new(true, true, false, false));
+    assert_eq!(a.gt_element_wise(b), Vector4::new(false, false, true, false));
+    assert_eq!(a.ge_element_wise(b), Vector4::new(false, true, true, false));
+    assert_eq!(a.eq_element_wise(b), Vector4::new(false, true, false, false));
+    assert_eq!(a.ne_element_wise(b), Vector4::new(true, false, true, true));
+    assert_eq!(vec2(1, 5).gt_element_wise(vec2(3, 3)), vec2(false, true));
+}
+
+#[test]
+fn test_mask_reductions() {
+    assert!(vec3(false, true, false).any());
+    assert!(!vec3(false, false, false).any());
+    assert!(vec3(true, true, true).all());
+    assert!(!vec3(true, false, true).all());
+    assert!(vec1(true).all());
+
+    let (a, b) = (vec2(true, false), vec2(true, true));
+    assert_eq!(!a, vec2(false, true));
+    assert_eq!(a & b, vec2(true, false));
+    assert_eq!(a | b, vec2(true, true));
+    assert_eq!(a ^ b, vec2(false, true));
+}
+
+#[test]
+fn test_select() {
+    let v = Vector3::new(-2.0f32, 0.5, 3.0);
+    let (lo, hi) = (Vector3::from_value(0.0), Vector3::from_value(1.0));
+    let clamped = v.lt_element_wise(lo).select(lo, v.gt_element_wise(hi).select(hi, v));
+    assert_eq!(clamped, Vector3::new(0.0, 0.5, 1.0));
+}
@@ -0,0 +1,197 @@
+// Copyright 2017 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use cgmath::wide::{Matrix4Lanes4, Matrix4Lanes8};
+
+fn vectors() -> [Vector3<f64>; 4] {
+    [Vector3::new(1.0, 2.0, 3.0),
+     Vector3::new(-4.0, 0.5, 2.0),
+     Vector3::new(0.0, 0.0, -7.0),
+     Vector3::new(3.0, -1.0, 0.25)]
+}
+
+fn others() -> [Vector3<f64>; 4] {
+    [Vector3::new(0.5, -1.0, 2.0),
+     Vector3::new(1.0, 1.0, 1.0),
+     Vector3::new(6.0, -2.0, 0.0),
+     Vector3::new(-3.0, 4.0, 8.0)]
+}
+
+fn quaternions() -> [Quaternion<f64>; 4] {
+    [Quaternion::from_axis_angle(Vector3::unit_x(), Deg(30.0)),
+     Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(-75.0)),
+     Quaternion::from_axis_angle(Vector3::new(0.0, 2.0, 3.0).normalize(), Deg(160.0)),
+     Quaternion::one()]
+}
+
+#[test]
+fn test_lanes() {
+    let mut wide = Vector3x4::from(vectors());
+    assert_eq!(wide.to_vectors(), vectors());
+    assert_eq!(wide.lane(1), vectors()[1]);
+
+    wide.set_lane(2, Vector3::new(9.0, 8.0, 7.0));
+    assert_eq!(wide.lane(2), Vector3::new(9.0, 8.0, 7.0));
+    assert_eq!(wide.z, [3.0, 2.0, 7.0, 0.25]);
+
+    let points = [Point3::new(1.0, 2.0, 3.0); 8];
+    assert_eq!(Vector3x8::from(points).to_points(), points);
+    assert_eq!(Vector3x8::splat(Vector3::new(1.0, 2.0, 3.0)), Vector3x8::from(points));
+}
+
+#[test]
+fn test_arithmetic() {
+    let (a, b) = (vectors(), others());
+    let sum = Vector3x4::from(a) + Vector3x4::from(b);
+    let difference = Vector3x4::from(a) - Vector3x4::from(b);
+    let scaled = Vector3x4::from(a) * 3.0;
+    let negated = -Vector3x4::from(a);
+    for i in 0..4 {
+        assert_eq!(sum.lane(i), a[i] + b[i]);
+        assert_eq!(difference.lane(i), a[i] - b[i]);
+        assert_eq!(scaled.lane(i), a[i] * 3.0);
+        assert_eq!(negated.lane(i), -a[i]);
+    }
+}
+
+#[test]
+fn test_dot_cross() {
+    let (a, b) = (vectors(), others());
+    let dot = Vector3x4::from(a).dot(Vector3x4::from(b));
+    let cross = Vector3x4::from(a).cross(Vector3x4::from(b));
+    let magnitude = Vector3x4::from(a).magnitude();
+    for i in 0..4 {
+        assert_eq!(dot[i], a[i].dot(b[i]));
+        assert_eq!(cross.lane(i), a[i].cross(b[i]));
+        assert_eq!(magnitude[i], a[i].magnitude());
+    }
+}
+
+#[test]
+fn test_normalize() {
+    let a = vectors();
+    let normalized = Vector3x4::from(a).normalize();
+    for (i, v) in a.iter().enumerate() {
+        assert_eq!(normalized.lane(i), v.normalize());
+    }
+}
+
+#[test]
+fn test_transform() {
+    let m = Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0)) *
+            Matrix4::from_axis_angle(Vector3::unit_y(), Deg(40.0)) *
+            Matrix4::from_nonuniform_scale(2.0, 1.0, 0.5);
+    let projection = perspective(Deg(60.0), 1.5, 0.1, 100.0);
+
+    let a = vectors();
+    let points = Vector3x4::from(a).transform_point(&m);
+    let projected = Vector3x4::from(a).transform_point(&projection);
+    let directions = Vector3x4::from(a).transform_vector(&m);
+    for (i, &v) in a.iter().enumerate() {
+        let p = Point3::from_vec(v);
+        assert_eq!(Point3::from_vec(points.lane(i)), m.transform_point(p));
+        assert_eq!(Point3::from_vec(projected.lane(i)), projection.transform_point(p));
+        assert_ulps_eq!(directions.lane(i), m.transform_vector(v));
+    }
+}
+
+#[test]
+fn test_quaternion() {
+    let (q, v) = (quaternions(), vectors());
+    let wide = Quaternionx4::from(q);
+    assert_eq!(wide.to_quaternions(), q);
+
+    let rotated = wide.rotate_vector(Vector3x4::from(v));
+    let product = wide * Quaternionx4::splat(q[1]);
+    let conjugate = wide.conjugate();
+    let dot = wide.dot(Quaternionx4::splat(q[2]));
+    for i in 0..4 {
+        assert_eq!(rotated.lane(i), q[i].rotate_vector(v[i]));
+        assert_eq!(product.lane(i), q[i] * q[1]);
+        assert_eq!(conjugate.lane(i), q[i].conjugate());
+        assert_eq!(dot[i], q[i].dot(q[2]));
+    }
+}
+
+#[test]
+fn test_quaternion_normalize() {
+    let q = [Quaternion::new(1.0f32, 2.0, 3.0, 4.0); 8];
+    let normalized = Quaternionx8::from(q).normalize();
+    let magnitude = normalized.magnitude();
+    for i in 0..8 {
+        assert_ulps_eq!(normalized.lane(i), q[i].normalize());
+        assert_ulps_eq!(magnitude[i], 1.0);
+    }
+}
+
+#[test]
+fn test_quaternion_dot_matches() {
+    let a = Quaternion::new(0.1f32, -0.7, 0.3, 1e7);
+    let b = Quaternion::new(0.3f32, 0.9, -0.2, 1e-7);
+    let dot = Quaternionx8::splat(a).dot(Quaternionx8::splat(b));
+    assert_eq!(dot[0].to_bits(), a.dot(b).to_bits());
+}
+
+fn matrices() -> [Matrix4<f64>; 4] {
+    [Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0)),
+     Matrix4::from_axis_angle(Vector3::unit_y(), Deg(40.0)) * Matrix4::from_scale(2.0),
+     Matrix4::from_nonuniform_scale(2.0, 1.0, 0.5) * Matrix4::from_angle_x(Deg(-70.0)),
+     perspective(Deg(60.0), 1.5, 0.1, 100.0)]
+}
+
+#[test]
+fn test_matrix_lanes() {
+    let mut wide = Matrix4Lanes4::from(matrices());
+    assert_eq!(wide.to_matrices(), matrices());
+    assert_eq!(wide.lane(3), matrices()[3]);
+    assert_eq!(wide.w[1], [-2.0, 0.0, 0.0, 0.0]);
+
+    wide.set_lane(0, Matrix4::identity());
+    assert_eq!(wide.lane(0), Matrix4::identity());
+    assert_eq!(wide.lane(1), matrices()[1]);
+
+    let m = Matrix4::from_scale(3.0f32);
+    assert_eq!(Matrix4Lanes8::splat(m).to_matrices(), [m; 8]);
+}
+
+#[test]
+fn test_matrix_transform() {
+    let (m, v) = (matrices(), vectors());
+    let wide = Matrix4Lanes4::from(m);
+    let points = wide.transform_point(Vector3x4::from(v));
+    let directions = wide.transform_vector(Vector3x4::from(v));
+    for i in 0..4 {
+        let p = Point3::from_vec(v[i]);
+        assert_eq!(Point3::from_vec(points.lane(i)), m[i].transform_point(p));
+        assert_eq!(directions.lane(i), m[i].transform_vector(v[i]));
+    }
+}
+
+#[test]
+fn test_matrix_mul() {
+    let m = matrices();
+    let bind = Matrix4::from_translation(Vector3::new(0.0, 1.0, 0.0)) *
+               Matrix4::from_angle_z(Deg(25.0));
+    let product = Matrix4Lanes4::from(m) * Matrix4Lanes4::splat(bind);
+    for (i, &m) in m.iter().enumerate() {
+        assert_eq!(product.lane(i), m * bind);
+    }
+    assert_ulps_eq!(product, Matrix4Lanes4::from([m[0] * bind, m[1] * bind, m[2] * bind, m[3] * bind]));
+}